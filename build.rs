@@ -0,0 +1,43 @@
+//! Looks up the versions of the auto splitting runtime and the WASM engine in
+//! the lock file, so the debugger can show which versions it is built with.
+
+use std::fs;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    let lock_file = fs::read_to_string("Cargo.lock").unwrap_or_default();
+    for (env_var, package) in [
+        ("RUNTIME_VERSION", "livesplit-auto-splitting"),
+        ("ENGINE_VERSION", "wasmtime"),
+    ] {
+        let version = package_version(&lock_file, package).unwrap_or_else(|| "unknown".into());
+        println!("cargo:rustc-env={env_var}={version}");
+    }
+}
+
+/// Finds the version of the package and, for git dependencies, the commit it
+/// is locked to.
+fn package_version(lock_file: &str, name: &str) -> Option<String> {
+    let package = lock_file
+        .split("[[package]]")
+        .find(|package| field(package, "name") == Some(name))?;
+    let mut version = field(package, "version")?.to_owned();
+    if let Some((_, commit)) = field(package, "source")
+        .filter(|source| source.starts_with("git+"))
+        .and_then(|source| source.rsplit_once('#'))
+    {
+        version.push_str(&format!(" ({})", &commit[..commit.len().min(9)]));
+    }
+    Some(version)
+}
+
+fn field<'a>(package: &'a str, key: &str) -> Option<&'a str> {
+    package.lines().find_map(|line| {
+        line.strip_prefix(key)?
+            .trim_start()
+            .strip_prefix('=')?
+            .trim()
+            .strip_prefix('"')?
+            .strip_suffix('"')
+    })
+}
@@ -0,0 +1,159 @@
+//! Auto splitters are sometimes distributed compressed. Gzip files and zip
+//! archives are detected by their magic numbers and transparently
+//! decompressed, so they can be loaded without extracting them first.
+
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Context};
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+/// The largest auto splitter that is going to be downloaded or decompressed.
+/// Compressed files can be tiny while expanding to gigabytes, so the output
+/// needs to be limited.
+pub const MAX_SIZE: u64 = 64 << 20;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// The WASM files of a zip archive along with the one that got loaded.
+pub struct Archive {
+    pub entries: Vec<String>,
+    pub selected: String,
+}
+
+/// Whether the data is a gzip file or a zip archive.
+pub fn is_compressed(data: &[u8]) -> bool {
+    data.starts_with(GZIP_MAGIC) || data.starts_with(ZIP_MAGIC)
+}
+
+/// Decompresses the data if it is compressed and returns it unchanged
+/// otherwise. For zip archives the `.wasm` entry called `entry` is picked. If
+/// there is no such entry, the first one is used instead.
+pub fn decompress(
+    data: Vec<u8>,
+    entry: Option<&str>,
+) -> anyhow::Result<(Vec<u8>, Option<Archive>)> {
+    decompress_with_limit(data, entry, MAX_SIZE)
+}
+
+fn decompress_with_limit(
+    data: Vec<u8>,
+    entry: Option<&str>,
+    limit: u64,
+) -> anyhow::Result<(Vec<u8>, Option<Archive>)> {
+    if data.starts_with(GZIP_MAGIC) {
+        let decompressed = read_limited(GzDecoder::new(&*data), limit)
+            .context("Failed decompressing the gzip file.")?;
+        Ok((decompressed, None))
+    } else if data.starts_with(ZIP_MAGIC) {
+        let mut archive =
+            ZipArchive::new(Cursor::new(data)).context("Failed reading the zip archive.")?;
+        let mut entries: Vec<String> = archive
+            .file_names()
+            .filter(|name| name.to_ascii_lowercase().ends_with(".wasm"))
+            .map(ToOwned::to_owned)
+            .collect();
+        entries.sort_unstable();
+        let selected = entry
+            .filter(|entry| entries.iter().any(|e| e == entry))
+            .or(entries.first().map(String::as_str))
+            .context("The zip archive doesn't contain a WASM file.")?
+            .to_owned();
+        let decompressed = archive
+            .by_name(&selected)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| read_limited(file, limit))
+            .with_context(|| format!("Failed extracting `{selected}` from the zip archive."))?;
+        ensure!(!decompressed.is_empty(), "`{selected}` is empty.");
+        Ok((decompressed, Some(Archive { entries, selected })))
+    } else {
+        Ok((data, None))
+    }
+}
+
+/// Reads everything, but fails once more than `limit` bytes are read.
+fn read_limited(reader: impl Read, limit: u64) -> anyhow::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    reader.take(limit + 1).read_to_end(&mut data)?;
+    ensure!(
+        data.len() as u64 <= limit,
+        "The decompressed file is larger than {limit} bytes."
+    );
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+    use zip::{write::FileOptions, ZipWriter};
+
+    use super::*;
+
+    const WASM: &[u8] = b"\0asm\x01\0\0\0";
+
+    fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in files {
+            writer.start_file(*name, FileOptions::default()).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_uncompressed() {
+        let (data, archive) = decompress(WASM.to_vec(), None).unwrap();
+        assert_eq!(data, WASM);
+        assert!(archive.is_none());
+    }
+
+    #[test]
+    fn test_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(WASM).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert!(is_compressed(&compressed));
+        let (data, archive) = decompress(compressed, None).unwrap();
+        assert_eq!(data, WASM);
+        assert!(archive.is_none());
+    }
+
+    #[test]
+    fn test_zip_entries() {
+        let compressed = zip(&[
+            ("readme.txt", b"hello"),
+            ("b.wasm", WASM),
+            ("a.wasm", b"\0asm\x01\0\0\0a"),
+        ]);
+        let (data, archive) = decompress(compressed.clone(), None).unwrap();
+        let archive = archive.unwrap();
+        assert_eq!(archive.entries, ["a.wasm", "b.wasm"]);
+        assert_eq!(archive.selected, "a.wasm");
+        assert_eq!(data, b"\0asm\x01\0\0\0a");
+
+        let (data, archive) = decompress(compressed, Some("b.wasm")).unwrap();
+        assert_eq!(archive.unwrap().selected, "b.wasm");
+        assert_eq!(data, WASM);
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&[0; 101]).unwrap();
+        let gzip = encoder.finish().unwrap();
+        assert!(decompress_with_limit(gzip.clone(), None, 101).is_ok());
+        assert!(decompress_with_limit(gzip, None, 100).is_err());
+
+        let zip = zip(&[("big.wasm", &[0; 101])]);
+        assert!(decompress_with_limit(zip.clone(), None, 101).is_ok());
+        assert!(decompress_with_limit(zip, None, 100).is_err());
+    }
+
+    #[test]
+    fn test_zip_without_wasm() {
+        assert!(decompress(zip(&[("readme.txt", b"hello")]), None).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! The configuration of the debugger that is persisted across sessions, as
+//! well as configuration profiles that can be applied all at once.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{ensure, Context};
+use eframe::egui::Key;
+use serde::{Deserialize, Serialize};
+
+/// The name of the application. The configuration is stored under it.
+pub const APP_NAME: &str = "Auto Splitting Runtime Debugger";
+
+/// The directory the configuration profiles are stored in. It's next to the
+/// configuration itself, so the profiles don't depend on the working
+/// directory.
+pub fn profiles_dir() -> PathBuf {
+    eframe::storage_dir(APP_NAME)
+        .unwrap_or_default()
+        .join("profiles")
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub always_on_top: bool,
+    pub theme: Theme,
+    pub zoom_factor: Option<f32>,
+    /// The size of the body text in points.
+    pub font_size: Option<f32>,
+    /// Whether the WASM file gets optimized. If this isn't specified, it's
+    /// only optimized outside of debug mode.
+    pub optimize: Option<bool>,
+    pub tick_rate_override: Option<Duration>,
+    /// The arrangement of the tabs, kept as raw JSON like in the profiles.
+    pub dock_layout: Option<serde_json::Value>,
+    /// The most recently loaded files, starting with the latest one.
+    pub recent_files: Vec<RecentFile>,
+    pub apply_settings_file: bool,
+    /// Whether changed files are only reloaded once the window regains focus.
+    pub reload_on_focus: bool,
+    /// Whether the auto splitter stops getting updated while the window is
+    /// not focused.
+    pub idle_when_unfocused: bool,
+    pub timer_hotkeys: TimerHotkeys,
+    /// The maximum amount of logs that are kept.
+    pub max_logs: Option<usize>,
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Follows the theme of the operating system.
+    System,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::System];
+
+    pub fn to_str(self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::System => "Follow System",
+        }
+    }
+}
+
+/// The keys that control the timer, so a run can be driven manually.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimerHotkeys {
+    pub start: Option<Key>,
+    pub split: Option<Key>,
+    pub skip_split: Option<Key>,
+    pub undo_split: Option<Key>,
+    pub reset: Option<Key>,
+}
+
+impl Default for TimerHotkeys {
+    fn default() -> Self {
+        Self {
+            start: Some(Key::F1),
+            split: Some(Key::F2),
+            skip_split: Some(Key::F3),
+            undo_split: Some(Key::F4),
+            reset: Some(Key::F5),
+        }
+    }
+}
+
+impl TimerHotkeys {
+    /// The keys in the order start, split, skip split, undo split and reset.
+    pub fn keys_mut(&mut self) -> [&mut Option<Key>; 5] {
+        [
+            &mut self.start,
+            &mut self.split,
+            &mut self.skip_split,
+            &mut self.undo_split,
+            &mut self.reset,
+        ]
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RecentFile {
+    pub wasm_path: PathBuf,
+    pub script_path: Option<PathBuf>,
+}
+
+impl AppConfig {
+    /// Reads a configuration that got exported as JSON.
+    pub fn import(path: &Path) -> anyhow::Result<Self> {
+        let json = fs::read_to_string(path).context("Failed reading the file.")?;
+        serde_json::from_str(&json).context("The configuration is invalid.")
+    }
+
+    /// Writes the configuration as JSON, so it can be moved to another
+    /// machine.
+    pub fn export(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).context("Failed writing the file.")
+    }
+}
+
+/// A named set of configuration that is stored as a JSON file in the profiles
+/// directory.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigProfile {
+    pub always_on_top: bool,
+    pub theme: Theme,
+    pub zoom_factor: Option<f32>,
+    /// The size of the body text in points.
+    pub font_size: Option<f32>,
+    pub tick_rate_override: Option<Duration>,
+    /// How often the runtime thread checks for work while no auto splitter is
+    /// loaded.
+    pub idle_tick_rate: Option<Duration>,
+    /// The arrangement of the tabs. It is kept as raw JSON, so a layout that
+    /// can't be restored doesn't invalidate the rest of the profile.
+    pub dock_layout: Option<serde_json::Value>,
+}
+
+impl ConfigProfile {
+    pub fn load(name: &str) -> anyhow::Result<Self> {
+        let json = fs::read_to_string(profile_path(name)?)
+            .with_context(|| format!("Failed reading the profile `{name}`."))?;
+        serde_json::from_str(&json).with_context(|| format!("The profile `{name}` is invalid."))
+    }
+
+    pub fn save(&self, name: &str) -> anyhow::Result<()> {
+        let path = profile_path(name)?;
+        fs::create_dir_all(profiles_dir()).context("Failed creating the profiles directory.")?;
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("Failed writing the profile `{name}`."))
+    }
+}
+
+/// Lists the names of all the stored configuration profiles.
+pub fn profile_names() -> Vec<String> {
+    let mut names = fs::read_dir(profiles_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "json" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_owned())
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
+fn profile_path(name: &str) -> anyhow::Result<PathBuf> {
+    ensure!(
+        !name.is_empty() && !name.contains(['/', '\\', '.']),
+        "The profile name `{name}` is not a valid file name."
+    );
+    Ok(profiles_dir().join(format!("{name}.json")))
+}
@@ -0,0 +1,146 @@
+//! Downloads auto splitters from a URL into a temporary file, so they can be
+//! loaded like any other file. This is meant for quickly trying out auto
+//! splitters that are shared as links.
+
+use std::{fs, io::Read, path::PathBuf, sync::mpsc, thread, time::Duration};
+
+use anyhow::{bail, ensure, Context};
+
+use crate::compression::{self, MAX_SIZE};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Content types that servers commonly use for WASM files and the compressed
+/// files they may be distributed as. Servers that don't know about WASM files
+/// usually fall back to a generic binary type.
+const CONTENT_TYPES: &[&str] = &[
+    "application/wasm",
+    "application/gzip",
+    "application/x-gzip",
+    "application/zip",
+    "application/octet-stream",
+    "binary/octet-stream",
+    "application/x-binary",
+];
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// A download that is running on a background thread.
+pub struct Download {
+    pub url: String,
+    receiver: mpsc::Receiver<anyhow::Result<PathBuf>>,
+}
+
+impl Download {
+    pub fn start(url: String) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread_url = url.clone();
+        thread::spawn(move || {
+            let _ = sender.send(download(&thread_url));
+        });
+        Self { url, receiver }
+    }
+
+    /// Returns the path of the downloaded file once the download is done.
+    pub fn try_finish(&self) -> Option<anyhow::Result<PathBuf>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Some(Err(anyhow::anyhow!("The download stopped unexpectedly.")))
+            }
+        }
+    }
+}
+
+fn download(url: &str) -> anyhow::Result<PathBuf> {
+    let response = ureq::get(url)
+        .timeout(TIMEOUT)
+        .call()
+        .context("Failed downloading the auto splitter.")?;
+
+    let content_type = response.content_type().to_owned();
+    if let Some(length) = response
+        .header("Content-Length")
+        .and_then(|length| length.parse::<u64>().ok())
+    {
+        ensure!(
+            length <= MAX_SIZE,
+            "The auto splitter is too large ({length} bytes)."
+        );
+    }
+
+    let mut data = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_SIZE + 1)
+        .read_to_end(&mut data)
+        .context("Failed downloading the auto splitter.")?;
+    ensure!(
+        data.len() as u64 <= MAX_SIZE,
+        "The auto splitter is too large."
+    );
+    check(&content_type, &data)?;
+
+    let dir = std::env::temp_dir().join("asr-debugger");
+    fs::create_dir_all(&dir).context("Failed creating the temporary directory.")?;
+    let path = dir.join(file_name(url));
+    fs::write(&path, data).context("Failed writing the temporary file.")?;
+    Ok(path)
+}
+
+/// Checks that the downloaded data is actually a (compressed) WASM file and
+/// not, for example, the HTML page the file is shown on.
+fn check(content_type: &str, data: &[u8]) -> anyhow::Result<()> {
+    let is_wasm = data.starts_with(WASM_MAGIC) || compression::is_compressed(data);
+    if !CONTENT_TYPES.contains(&content_type) && !is_wasm {
+        bail!("The URL doesn't point to a WASM file, but to content of the type `{content_type}`.");
+    }
+    ensure!(is_wasm, "The downloaded file is not a WASM file.");
+    Ok(())
+}
+
+/// The name of the temporary file, based on the last segment of the URL's
+/// path.
+fn file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let name = path
+        .rsplit('/')
+        .next()
+        .filter(|name| {
+            [".wasm", ".gz", ".zip"]
+                .iter()
+                .any(|extension| name.len() > extension.len() && name.ends_with(extension))
+                && !name.contains(['\\', ':'])
+        })
+        .unwrap_or("download.wasm");
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(
+            file_name("https://example.com/releases/splitter.wasm?raw=true"),
+            "splitter.wasm"
+        );
+        assert_eq!(file_name("https://example.com/download"), "download.wasm");
+        assert_eq!(file_name("https://example.com/.wasm"), "download.wasm");
+        assert_eq!(
+            file_name("https://example.com/splitter.wasm.gz"),
+            "splitter.wasm.gz"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check("application/wasm", b"\0asm\x01\0\0\0").is_ok());
+        assert!(check("text/plain", b"\0asm\x01\0\0\0").is_ok());
+        assert!(check("text/html", b"<!DOCTYPE html>").is_err());
+        assert!(check("application/octet-stream", b"PK\x03\x04").is_ok());
+        assert!(check("application/octet-stream", b"MZ").is_err());
+    }
+}
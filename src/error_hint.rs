@@ -0,0 +1,114 @@
+//! Extracts a short hint from error logs, so the actual cause of an error
+//! doesn't get lost in the full error chain.
+
+pub struct ErrorHint<'a> {
+    /// The innermost cause of the error.
+    pub cause: &'a str,
+    /// Where in the WASM module the error happened, if known.
+    pub location: Option<&'a str>,
+}
+
+/// Parses an error that is formatted with `{:?}` by `anyhow`. Errors without
+/// any causes don't need a hint, so `None` is returned for them.
+pub fn parse(message: &str) -> Option<ErrorHint<'_>> {
+    let chain = chain(message);
+    let [_, .., cause] = chain[..] else {
+        return None;
+    };
+
+    Some(ErrorHint {
+        cause,
+        location: wasm_location(message),
+    })
+}
+
+/// Splits an error that is formatted with `{:?}` by `anyhow` into the error
+/// itself followed by each of its causes. Lines that continue a cause, such as
+/// the frames of a backtrace, are not included.
+pub fn chain(message: &str) -> Vec<&str> {
+    let Some((error, causes)) = message.split_once("\nCaused by:\n") else {
+        return vec![message.trim()];
+    };
+    let mut chain = vec![error.trim()];
+    chain.extend(causes.lines().filter_map(|line| {
+        let line = line.strip_prefix("    ")?;
+        match line.split_once(": ") {
+            Some((index, cause)) if index.parse::<usize>().is_ok() => Some(cause.trim()),
+            _ if !line.starts_with(' ') => Some(line.trim()),
+            _ => None,
+        }
+    }));
+    chain
+}
+
+/// Finds where in the WASM module an error happened, which is either the
+/// function at the top of the WASM backtrace or the offset of invalid code.
+pub fn wasm_location(message: &str) -> Option<&str> {
+    if let Some((_, backtrace)) = message.split_once("wasm backtrace:") {
+        let frame = backtrace.lines().map(str::trim).find(|l| !l.is_empty())?;
+        return Some(
+            frame
+                .split_once(" - ")
+                .map_or(frame, |(_, function)| function),
+        );
+    }
+
+    let start = message.find("offset 0x")?;
+    let offset = &message[start..];
+    let end = offset[9..]
+        .find(|c: char| !c.is_ascii_hexdigit())
+        .map_or(offset.len(), |end| end + 9);
+    Some(&offset[..end])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_without_causes() {
+        assert!(parse("Failed loading the auto splitter.").is_none());
+    }
+
+    #[test]
+    fn test_parse_single_cause() {
+        let hint = parse(
+            "Failed loading the auto splitter.\n\nCaused by:\n    invalid input WebAssembly code at offset 0x1f3a",
+        )
+        .unwrap();
+        assert_eq!(
+            hint.cause,
+            "invalid input WebAssembly code at offset 0x1f3a"
+        );
+        assert_eq!(hint.location, Some("offset 0x1f3a"));
+    }
+
+    #[test]
+    fn test_parse_multiple_causes() {
+        let hint = parse(
+            "Failed executing the auto splitter.\n\nCaused by:\n    0: error while executing at wasm backtrace:\n           0:  0x2c8 - splitter!update\n           1:  0x31a - splitter!tick\n    1: wasm trap: wasm `unreachable` instruction executed",
+        )
+        .unwrap();
+        assert_eq!(
+            hint.cause,
+            "wasm trap: wasm `unreachable` instruction executed"
+        );
+        assert_eq!(hint.location, Some("splitter!update"));
+    }
+
+    #[test]
+    fn test_chain() {
+        assert_eq!(
+            chain("Failed starting the auto splitter.\n\nCaused by:\n    0: error while executing at wasm backtrace:\n           0:  0x2c8 - splitter!_start\n    1: wasm trap: out of bounds memory access"),
+            [
+                "Failed starting the auto splitter.",
+                "error while executing at wasm backtrace:",
+                "wasm trap: out of bounds memory access",
+            ]
+        );
+        assert_eq!(
+            chain("Failed loading the auto splitter."),
+            ["Failed loading the auto splitter."]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Runs an auto splitter without the user interface for a fixed duration or
+//! amount of ticks and prints the logs and variables once it is done. This
+//! allows smoke testing auto splitters in automated environments.
+
+use std::{
+    fs,
+    sync::{atomic, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use livesplit_auto_splitting::AutoSplitter;
+
+use crate::{
+    build_runtime, compression, fmt_log_time, read_wasm_file, settings_json, Args, DebuggerTimer,
+    LogLevel, LogType, SharedState,
+};
+
+/// How long the auto splitter runs if neither a duration nor an amount of ticks
+/// is specified.
+const DEFAULT_DURATION: Duration = Duration::from_secs(10);
+
+/// How long the auto splitter may take to reach the amount of ticks if no
+/// duration is specified, so an auto splitter that is stuck can't block
+/// forever.
+const TICKS_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often it is checked whether the run is done.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs the auto splitter on the runtime thread until it's done and returns
+/// the exit code of the process. It is nonzero if any error was logged.
+pub fn run(args: &Args, shared_state: &SharedState, timer: &DebuggerTimer) -> i32 {
+    if let Some(tick_rate) = args.tick_rate {
+        *shared_state.tick_rate_override.lock().unwrap() = Some(tick_rate);
+    }
+
+    match load(args, timer) {
+        Ok(auto_splitter) => {
+            shared_state
+                .auto_splitter
+                .store(Some(Arc::new(auto_splitter)));
+
+            let duration = args.duration.unwrap_or(if args.ticks.is_some() {
+                TICKS_TIMEOUT
+            } else {
+                DEFAULT_DURATION
+            });
+            let start = Instant::now();
+            loop {
+                thread::sleep(POLL_INTERVAL);
+                let tick_count = shared_state.tick_count.load(atomic::Ordering::Relaxed);
+                if args.ticks.is_some_and(|ticks| tick_count >= ticks) {
+                    break;
+                }
+                if start.elapsed() >= duration {
+                    if let (Some(ticks), None) = (args.ticks, args.duration) {
+                        timer.0.write().unwrap().log(
+                            format!(
+                                "The auto splitter only reached {tick_count} of {ticks} ticks within {} seconds.",
+                                TICKS_TIMEOUT.as_secs(),
+                            ),
+                            LogType::Runtime(LogLevel::Error),
+                        );
+                    }
+                    break;
+                }
+            }
+
+            shared_state.kill_auto_splitter_if_it_doesnt_react(timer);
+            shared_state.auto_splitter.store(None);
+        }
+        Err(e) => timer
+            .0
+            .write()
+            .unwrap()
+            .log(format!("{e:?}"), LogType::Runtime(LogLevel::Error)),
+    }
+
+    let state = timer.0.read().unwrap();
+    println!("Logs");
+    for log in &state.logs {
+        println!(
+            "  {} [{}] {}",
+            fmt_log_time(log.time, false),
+            log.ty.to_str(),
+            log.message,
+        );
+    }
+    println!();
+    println!("Variables");
+    for (key, variable) in &state.variables {
+        println!("  {key}: {}", variable.value);
+    }
+
+    // The logs may have been trimmed, so the errors are counted separately.
+    i32::from(state.error_count != 0)
+}
+
+fn load(args: &Args, timer: &DebuggerTimer) -> anyhow::Result<AutoSplitter<DebuggerTimer>> {
+    let path = args
+        .wasm_path
+        .as_ref()
+        .context("The headless mode requires a WASM file.")?;
+    let (data, _) = compression::decompress(read_wasm_file(path)?, None)?;
+    let module = build_runtime(!args.debug)
+        .compile(&data)
+        .context("Failed loading the auto splitter.")?;
+
+    let settings_map = match &args.settings {
+        Some(path) => Some(
+            fs::read_to_string(path)
+                .context("Failed reading the settings file.")
+                .and_then(|json| settings_json::parse_map(&json))
+                .context("Failed loading the settings.")?,
+        ),
+        None => None,
+    };
+
+    module
+        .instantiate(timer.clone(), settings_map, args.script.as_deref())
+        .context("Failed starting the auto splitter.")
+}
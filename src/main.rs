@@ -1,43 +1,66 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap, VecDeque},
     fmt,
     fs::{self, File},
-    io::Write,
-    path::PathBuf,
+    io::{self, Write},
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
     sync::{
-        atomic::{AtomicU64, AtomicUsize},
-        Arc, Mutex, RwLock,
+        atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize},
+        mpsc, Arc, Mutex, OnceLock, RwLock,
     },
     thread,
     time::{Duration, Instant, SystemTime},
 };
 
-use anyhow::Context;
+use anyhow::{ensure, Context};
 use arc_swap::ArcSwapOption;
 use atomic::Atomic;
 use clap::Parser;
 use clear_vec::{Clear, ClearVec};
+use compression::Archive;
+use config::{AppConfig, ConfigProfile, RecentFile, Theme, TimerHotkeys};
+use download::Download;
 use eframe::{
-    egui::{self, ComboBox, Grid, RichText, TextStyle, Visuals},
+    egui::{self, CollapsingHeader, ComboBox, Grid, RichText, TextStyle, Visuals},
     emath::Align,
     epaint::{FontFamily, FontId},
     App, Frame,
 };
-use egui_dock::{DockArea, DockState, NodeIndex, Style};
+use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex};
 use egui_file::FileDialog;
-use egui_plot::{Bar, BarChart, Legend, Plot, VLine};
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotBounds, PlotPoints, VLine};
 use hdrhistogram::Histogram;
 use indexmap::IndexMap;
 use livesplit_auto_splitting::{
     settings, time, wasi_path, AutoSplitter, CompiledAutoSplitter, Config, ExecutionGuard, Runtime,
     Timer, TimerState,
 };
+use module_info::ModuleInfo;
+use profile::ProfileRun;
+use serde::{Deserialize, Serialize};
+use snapshot::{Snapshot, SnapshotInfo};
 
 mod clear_vec;
+mod compression;
+mod config;
+mod download;
+mod error_hint;
 mod file_filter;
+mod headless;
+mod module_info;
+mod percentile_plot;
+mod profile;
+mod run_state;
+mod settings_code;
+mod settings_diff;
+mod settings_json;
+mod snapshot;
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 enum Tab {
     Main,
     Statistics,
@@ -47,102 +70,300 @@ enum Tab {
     SettingsMap,
     Processes,
     Performance,
+    Memory,
+    Module,
 }
 
+impl Tab {
+    /// All the tabs, in the order of their Alt + number keyboard shortcuts.
+    const ALL: [Tab; 10] = [
+        Tab::Main,
+        Tab::Statistics,
+        Tab::Logs,
+        Tab::Variables,
+        Tab::SettingsGUI,
+        Tab::SettingsMap,
+        Tab::Processes,
+        Tab::Performance,
+        Tab::Memory,
+        Tab::Module,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Tab::Main => "Main",
+            Tab::Statistics => "Statistics",
+            Tab::Logs => "Logs",
+            Tab::Variables => "Variables",
+            Tab::SettingsGUI => "Settings GUI",
+            Tab::SettingsMap => "Settings Map",
+            Tab::Processes => "Processes",
+            Tab::Performance => "Performance",
+            Tab::Memory => "Memory",
+            Tab::Module => "Module",
+        }
+    }
+}
+
+fn default_dock_state() -> DockState<Tab> {
+    let mut dock_state =
+        DockState::new(vec![Tab::Main, Tab::Performance, Tab::Memory, Tab::Module]);
+    let tree = dock_state.main_surface_mut();
+    let [left, right] = tree.split_right(NodeIndex::root(), 0.65, vec![Tab::SettingsGUI]);
+    tree.split_below(right, 0.5, vec![Tab::Variables, Tab::SettingsMap]);
+    tree.split_below(left, 0.5, vec![Tab::Logs, Tab::Statistics, Tab::Processes]);
+    dock_state
+}
+
+const TAB_SHORTCUT_KEYS: [egui::Key; 10] = [
+    egui::Key::Num1,
+    egui::Key::Num2,
+    egui::Key::Num3,
+    egui::Key::Num4,
+    egui::Key::Num5,
+    egui::Key::Num6,
+    egui::Key::Num7,
+    egui::Key::Num8,
+    egui::Key::Num9,
+    egui::Key::Num0,
+];
+
 #[derive(Parser)]
 struct Args {
     #[arg(short, long)]
     debug: bool,
+    /// Publishes the state of the run as JSON to all TCP clients connecting to
+    /// this address, e.g. 127.0.0.1:5000.
+    #[arg(long)]
+    run_state_address: Option<String>,
+    /// Overrides the tick rate requested by the auto splitter.
+    #[arg(long, value_name = "HZ", value_parser = parse_tick_rate)]
+    tick_rate: Option<Duration>,
+    /// Applies the settings map stored in this JSON file after loading the
+    /// auto splitter.
+    #[arg(long, value_name = "PATH")]
+    settings: Option<PathBuf>,
+    /// The script to provide to the auto splitter.
+    #[arg(long, value_name = "PATH")]
+    script: Option<PathBuf>,
+    /// Runs the auto splitter without opening a window and prints the logs
+    /// and variables once it is done. The exit code is nonzero if any error
+    /// was logged.
+    #[arg(long, requires = "wasm_path")]
+    headless: bool,
+    /// How many seconds the auto splitter runs in headless mode.
+    #[arg(long, value_name = "SECS", value_parser = parse_duration, requires = "headless")]
+    duration: Option<Duration>,
+    /// How many ticks the auto splitter runs in headless mode. Unless a
+    /// duration is specified as well, the run fails if the ticks aren't
+    /// reached within a minute.
+    #[arg(long, requires = "headless")]
+    ticks: Option<u64>,
     wasm_path: Option<PathBuf>,
 }
 
+fn parse_positive(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(value) if value > 0.0 && value.is_finite() => Ok(value),
+        _ => Err("The value needs to be a positive number.".into()),
+    }
+}
+
+/// Parses a positive amount of seconds.
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(parse_positive(value)?)
+        .map_err(|_| "The duration is out of range.".into())
+}
+
+/// Parses a tick rate in hertz into the duration between two ticks.
+fn parse_tick_rate(value: &str) -> Result<Duration, String> {
+    let hertz = parse_positive(value)?;
+    Duration::try_from_secs_f64(hertz.recip())
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| "The tick rate is out of range.".into())
+}
+
+/// The offset of the local time zone. It needs to be determined before any
+/// other threads are spawned, as it can't be soundly queried afterwards on some
+/// platforms.
+static LOCAL_OFFSET: OnceLock<time::UtcOffset> = OnceLock::new();
+
 fn main() {
+    LOCAL_OFFSET
+        .get_or_init(|| time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC));
+
     let args = Args::parse();
 
     let shared_state = Arc::new(SharedState {
         auto_splitter: ArcSwapOption::new(None),
         memory_usage: AtomicUsize::new(0),
+        peak_memory_usage: AtomicUsize::new(0),
+        memory_history: Mutex::new(VecDeque::with_capacity(MEMORY_HISTORY_CAPACITY)),
+        memory_leak_suspected: AtomicBool::new(false),
         handles: AtomicU64::new(0),
+        handles_delta: AtomicI64::new(0),
         tick_rate: Mutex::new(std::time::Duration::ZERO),
+        effective_tick_rate: Mutex::new(std::time::Duration::ZERO),
+        injected_delay: Mutex::new(std::time::Duration::ZERO),
+        process_refresh_interval: Mutex::new(std::time::Duration::ZERO),
+        tick_rate_override: Mutex::new(None),
+        idle_tick_rate: Mutex::new(DEFAULT_IDLE_TICK_RATE),
         slowest_tick: Mutex::new(std::time::Duration::ZERO),
+        slowest_tick_frozen: AtomicBool::new(false),
         avg_tick_secs: Atomic::new(0.0),
+        tick_count: AtomicU64::new(0),
+        loaded_at: Mutex::new(None),
         tick_times: Mutex::new(Histogram::new(1).unwrap()),
+        recent_ticks: Mutex::new(VecDeque::with_capacity(RECENT_TICKS_CAPACITY)),
+        recent_tick_breakdowns: Mutex::new(VecDeque::with_capacity(RECENT_TICKS_CAPACITY)),
+        slow_ticks: Mutex::new(VecDeque::with_capacity(SLOW_TICKS_CAPACITY)),
+        slow_tick_threshold: Mutex::new(DEFAULT_SLOW_TICK_THRESHOLD),
         processes: Mutex::new(ClearVec::new()),
+        restart_on_crash: AtomicBool::new(false),
+        idle_when_unfocused: AtomicBool::new(false),
+        window_focused: AtomicBool::new(true),
+        paused: AtomicBool::new(false),
+        pending_steps: AtomicUsize::new(0),
+        profile: Mutex::new(None),
+        tick_started_at: Mutex::new(None),
+        tick_requested: AtomicBool::new(false),
+        runtime_thread: OnceLock::new(),
+        interrupted_for_hang: AtomicBool::new(false),
     });
     let timer = DebuggerTimer::default();
+    let (restart_sender, restart_receiver) = mpsc::channel();
+
+    if let Some(address) = &args.run_state_address {
+        if let Err(e) = run_state::spawn_server(address, timer.clone()) {
+            timer.0.write().unwrap().log(
+                format!("Failed to publish the run state on {address}: {e}"),
+                LogType::Runtime(LogLevel::Error),
+            );
+        }
+    }
 
     thread::Builder::new()
         .name("Auto Splitter Thread".into())
         .spawn({
             let timer = timer.clone();
             let shared_state = shared_state.clone();
-            move || runtime_thread(shared_state, timer.clone())
+            move || runtime_thread(shared_state, timer.clone(), restart_sender)
         })
         .unwrap();
 
+    if args.headless {
+        std::process::exit(headless::run(&args, &shared_state, &timer));
+    }
+
     let options = eframe::NativeOptions::default();
     eframe::run_native(
-        "Auto Splitting Runtime Debugger",
+        config::APP_NAME,
         options,
         Box::new(move |cc| {
-            cc.egui_ctx.set_visuals(Visuals::dark());
-            let mut style = (*cc.egui_ctx.style()).clone();
-
-            let mut text_styles = BTreeMap::new();
-            text_styles.insert(
-                TextStyle::Small,
-                FontId::new(1.25 * 10.0, FontFamily::Proportional),
-            );
-            text_styles.insert(
-                TextStyle::Body,
-                FontId::new(1.25 * 14.0, FontFamily::Proportional),
-            );
-            text_styles.insert(
-                TextStyle::Button,
-                FontId::new(1.25 * 14.0, FontFamily::Proportional),
-            );
-            text_styles.insert(
-                TextStyle::Heading,
-                FontId::new(1.25 * 20.0, FontFamily::Proportional),
-            );
-            text_styles.insert(
-                TextStyle::Monospace,
-                FontId::new(1.25 * 14.0, FontFamily::Monospace),
-            );
-            // Redefine text_styles
-            style.text_styles = text_styles;
+            let mut config: AppConfig = cc
+                .storage
+                .and_then(|storage| eframe::get_value(storage, eframe::APP_KEY))
+                .unwrap_or_default();
+            let optimize_preference = config.optimize;
+            if args.debug {
+                // Debug mode always starts unoptimized, so the source code can
+                // be stepped through.
+                config.optimize = None;
+            }
 
-            // Mutate global style with above changes
-            cc.egui_ctx.set_style(style);
+            // The zoom shortcuts are handled by the debugger itself, so the
+            // zoom factor can be clamped and persisted.
+            cc.egui_ctx.options_mut(|o| o.zoom_with_keyboard = false);
 
-            let mut dock_state = DockState::new(vec![Tab::Main, Tab::Performance]);
-            let tree = dock_state.main_surface_mut();
-            let [left, right] = tree.split_right(NodeIndex::root(), 0.65, vec![Tab::SettingsGUI]);
-            tree.split_below(right, 0.5, vec![Tab::Variables, Tab::SettingsMap]);
-            tree.split_below(left, 0.5, vec![Tab::Logs, Tab::Statistics, Tab::Processes]);
+            set_font_size(&cc.egui_ctx, DEFAULT_FONT_SIZE);
 
             let optimize = !args.debug;
 
             let mut app = Box::new(Debugger {
-                dock_state,
+                dock_state: default_dock_state(),
+                tab_locations: HashMap::new(),
                 state: AppState {
                     path: None,
                     script_path: None,
                     module_modified_time: None,
                     script_modified_time: None,
+                    pending_module_change: None,
+                    pending_script_change: None,
+                    reload_on_focus: false,
+                    was_focused: true,
                     optimize,
+                    optimize_preference,
+                    debug: args.debug,
                     open_file_dialog: None,
                     module: None,
+                    module_info: None,
+                    archive: None,
+                    load_error: None,
+                    download_url: String::new(),
+                    show_about: false,
+                    download: None,
                     shared_state,
                     timer,
                     runtime: build_runtime(optimize),
+                    restart_receiver,
+                    row_budget: RowBudget::default(),
+                    performance_plot_rect: None,
+                    plot_image_request: None,
+                    profile_report: None,
+                    settings_snapshot: None,
+                    statistics_precision: DurationPrecision::Nanoseconds,
+                    performance_precision: DurationPrecision::Nanoseconds,
+                    show_raw_ticks: false,
+                    show_tick_breakdown: false,
+                    show_slow_ticks: false,
+                    log_filter: LogFilterPreset::All.filter(),
+                    log_search: String::new(),
+                    show_raw_game_time: false,
+                    show_log_dates: false,
+                    group_repeated_logs: true,
+                    pinned_y_range: None,
+                    settings_display: SettingsMapDisplay::default(),
+                    profile_secs: 10,
+                    always_on_top: false,
+                    theme: Theme::default(),
+                    zoom_factor: 1.0,
+                    font_size: DEFAULT_FONT_SIZE,
+                    timer_hotkeys: TimerHotkeys::default(),
+                    recent_files: Vec::new(),
+                    memory_view: MemoryView::default(),
+                    variable_search: String::new(),
+                    variable_search_values: false,
+                    sort_variables: false,
+                    plotted_variable: None,
+                    apply_settings_file: false,
+                    config_profile_name: String::new(),
+                    config_profile_request: None,
+                    settings_widgets: Vec::new(),
                 },
             });
 
+            app.apply_config(&cc.egui_ctx, config);
+
+            if let Some(tick_rate) = args.tick_rate {
+                *app.state.shared_state.tick_rate_override.lock().unwrap() = Some(tick_rate);
+            }
+
+            if let Some(script_path) = args.script {
+                app.state.script_modified_time = fs::metadata(&script_path)
+                    .ok()
+                    .and_then(|m| m.modified().ok());
+                app.state.script_path = Some(script_path);
+            }
+
             if let Some(path) = args.wasm_path {
                 app.state.load(Load::File(path));
             }
 
+            if let Some(path) = args.settings {
+                app.state.apply_settings_map_file(&path);
+            }
+
             app
         }),
     )
@@ -153,8 +374,49 @@ fn main() {
 struct ProcessInfo {
     path: String,
     pid: String,
+    first_seen: Option<Instant>,
+    last_seen: Option<Instant>,
+    attached: bool,
+}
+
+/// Remembers when a process was seen by the auto splitter. Detached processes
+/// are kept around for a while, so processes that keep attaching and detaching
+/// are easy to spot.
+struct SeenProcess {
+    path: String,
+    first_seen: Instant,
+    last_seen: Instant,
 }
 
+/// The amount of the most recent tick durations that are kept around.
+const RECENT_TICKS_CAPACITY: usize = 100;
+
+/// How often the runtime thread checks whether the window got focused again
+/// while idling. Regaining focus wakes it up right away anyway.
+const UNFOCUSED_IDLE_RATE: Duration = Duration::from_millis(500);
+
+/// The amount of the most recent slow ticks that are kept around.
+const SLOW_TICKS_CAPACITY: usize = 50;
+/// How long a tick needs to take by default to be recorded as slow.
+const DEFAULT_SLOW_TICK_THRESHOLD: Duration = Duration::from_millis(5);
+
+/// The amount of the most recent numeric values that are kept around for each
+/// variable, so they can be plotted.
+const VARIABLE_HISTORY_CAPACITY: usize = 500;
+
+/// How often the memory usage is sampled for detecting leaks.
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The amount of memory usage samples a leak is detected over.
+const MEMORY_HISTORY_CAPACITY: usize = 60;
+
+/// How many times the memory usage needs to grow within the samples for a leak
+/// to be suspected. WASM memory never shrinks, so a single growth is normal.
+const MEMORY_LEAK_MIN_GROWTHS: usize = 5;
+
+/// How long detached processes are still shown in the Processes tab.
+const PROCESS_HISTORY_RETENTION: Duration = Duration::from_secs(30);
+
 impl Clear for ProcessInfo {
     fn clear(&mut self) {
         self.path.clear();
@@ -165,21 +427,195 @@ impl Clear for ProcessInfo {
 struct SharedState {
     auto_splitter: ArcSwapOption<AutoSplitter<DebuggerTimer>>,
     tick_rate: Mutex<std::time::Duration>,
+    effective_tick_rate: Mutex<std::time::Duration>,
+    injected_delay: Mutex<std::time::Duration>,
+    /// How often the information about the attached processes is collected.
+    /// If it's zero, it is collected on every tick.
+    process_refresh_interval: Mutex<std::time::Duration>,
+    /// Replaces the tick rate requested by the auto splitter if set.
+    tick_rate_override: Mutex<Option<std::time::Duration>>,
+    /// How often the runtime thread checks for work while no auto splitter is
+    /// loaded.
+    idle_tick_rate: Mutex<std::time::Duration>,
     slowest_tick: Mutex<std::time::Duration>,
+    slowest_tick_frozen: AtomicBool,
     memory_usage: AtomicUsize,
+    peak_memory_usage: AtomicUsize,
+    /// The memory usage sampled every [`MEMORY_SAMPLE_INTERVAL`].
+    memory_history: Mutex<VecDeque<(Instant, usize)>>,
+    /// Whether the memory usage kept growing over all of the samples.
+    memory_leak_suspected: AtomicBool,
     handles: AtomicU64,
+    /// How much the amount of handles changed during the last tick. The
+    /// runtime doesn't expose which kinds of handles there are, so this is the
+    /// closest to seeing which handles get created or leaked.
+    handles_delta: AtomicI64,
     avg_tick_secs: Atomic<f64>,
+    /// The amount of calls to the update function of the current auto splitter.
+    tick_count: AtomicU64,
+    /// When the current auto splitter was loaded.
+    loaded_at: Mutex<Option<Instant>>,
     tick_times: Mutex<Histogram<u64>>,
+    recent_ticks: Mutex<VecDeque<(SystemTime, std::time::Duration)>>,
+    /// The most recent ticks that took at least as long as the threshold.
+    slow_ticks: Mutex<VecDeque<SlowTick>>,
+    slow_tick_threshold: Mutex<std::time::Duration>,
+    /// Where the time of the most recent ticks was spent.
+    recent_tick_breakdowns: Mutex<VecDeque<TickBreakdown>>,
     processes: Mutex<ClearVec<ProcessInfo>>,
+    restart_on_crash: AtomicBool,
+    /// Whether the auto splitter stops getting updated while the window of the
+    /// debugger is not focused.
+    idle_when_unfocused: AtomicBool,
+    window_focused: AtomicBool,
+    paused: AtomicBool,
+    /// The amount of updates that are still to be executed while paused.
+    pending_steps: AtomicUsize,
+    profile: Mutex<Option<ProfileRun>>,
+    /// When the tick that is currently running started.
+    tick_started_at: Mutex<Option<Instant>>,
+    /// Whether the next tick should happen right away instead of waiting for
+    /// the tick rate.
+    tick_requested: AtomicBool,
+    runtime_thread: OnceLock<thread::Thread>,
+    /// Whether the previous auto splitter got interrupted when it got replaced,
+    /// because its tick didn't finish in time.
+    interrupted_for_hang: AtomicBool,
+}
+
+/// A tick that took at least as long as the slow tick threshold, along with
+/// what the auto splitter was doing at that moment.
+struct SlowTick {
+    time: SystemTime,
+    duration: std::time::Duration,
+    split_index: usize,
+    attached_processes: usize,
+}
+
+#[derive(Copy, Clone)]
+struct TickBreakdown {
+    /// Waiting for the lock on the auto splitter.
+    lock: std::time::Duration,
+    /// Executing the `update` function of the auto splitter.
+    update: std::time::Duration,
+    /// Collecting the attached processes.
+    processes: std::time::Duration,
+}
+
+fn log_process_event(timer: &DebuggerTimer, event: &str, process: &ProcessInfo) {
+    timer.0.write().unwrap().log(
+        format!("Process {event}: {} (PID {}).", process.path, process.pid),
+        LogType::Process,
+    );
 }
 
 impl SharedState {
-    fn kill_auto_splitter_if_it_doesnt_react(&self) {
+    /// Whether the auto splitter may be updated. While paused, every requested
+    /// step allows for a single update.
+    fn may_update(&self) -> bool {
+        !self.paused.load(atomic::Ordering::Relaxed)
+            || self
+                .pending_steps
+                .fetch_update(
+                    atomic::Ordering::Relaxed,
+                    atomic::Ordering::Relaxed,
+                    |steps| steps.checked_sub(1),
+                )
+                .is_ok()
+    }
+
+    fn is_idling_while_unfocused(&self) -> bool {
+        self.idle_when_unfocused.load(atomic::Ordering::Relaxed)
+            && !self.window_focused.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Runs the next tick right away instead of waiting for the tick rate.
+    fn request_tick(&self) {
+        self.tick_requested.store(true, atomic::Ordering::Relaxed);
+        if let Some(thread) = self.runtime_thread.get() {
+            thread.unpark();
+        }
+    }
+
+    /// Makes all the locks usable again after a thread panicked while holding
+    /// one of them.
+    fn clear_poison(&self) {
+        self.tick_rate.clear_poison();
+        self.effective_tick_rate.clear_poison();
+        self.injected_delay.clear_poison();
+        self.process_refresh_interval.clear_poison();
+        self.tick_rate_override.clear_poison();
+        self.idle_tick_rate.clear_poison();
+        self.slowest_tick.clear_poison();
+        self.memory_history.clear_poison();
+        self.loaded_at.clear_poison();
+        self.tick_times.clear_poison();
+        self.recent_ticks.clear_poison();
+        self.slow_ticks.clear_poison();
+        self.slow_tick_threshold.clear_poison();
+        self.recent_tick_breakdowns.clear_poison();
+        self.processes.clear_poison();
+        self.profile.clear_poison();
+        self.tick_started_at.clear_poison();
+    }
+
+    fn reset_statistics(&self) {
+        // A frozen slowest tick is kept on purpose, so it survives resets. It
+        // can still be reset explicitly.
+        if !self.slowest_tick_frozen.load(atomic::Ordering::Relaxed) {
+            *self.slowest_tick.lock().unwrap() = std::time::Duration::ZERO;
+        }
+        self.avg_tick_secs.store(0.0, atomic::Ordering::Relaxed);
+        self.peak_memory_usage.store(0, atomic::Ordering::Relaxed);
+        self.memory_history.lock().unwrap().clear();
+        self.memory_leak_suspected
+            .store(false, atomic::Ordering::Relaxed);
+        self.tick_times.lock().unwrap().clear();
+        self.recent_ticks.lock().unwrap().clear();
+        self.slow_ticks.lock().unwrap().clear();
+        self.recent_tick_breakdowns.lock().unwrap().clear();
+    }
+
+    /// Samples the memory usage if enough time has passed since the last
+    /// sample. Returns `true` if a leak is suspected for the first time, which
+    /// is the case once the memory usage kept growing in several steps without
+    /// ever shrinking over all of the samples.
+    fn sample_memory_usage(&self, memory_usage: usize) -> bool {
+        let mut history = self.memory_history.lock().unwrap();
+        if history
+            .back()
+            .is_some_and(|&(time, _)| time.elapsed() < MEMORY_SAMPLE_INTERVAL)
+        {
+            return false;
+        }
+        if history.len() == MEMORY_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back((Instant::now(), memory_usage));
+
+        let steps = || history.iter().zip(history.iter().skip(1));
+        let growing = history.len() == MEMORY_HISTORY_CAPACITY
+            && steps().all(|(a, b)| a.1 <= b.1)
+            && steps().filter(|(a, b)| a.1 < b.1).count() >= MEMORY_LEAK_MIN_GROWTHS;
+        let suspected_before = self
+            .memory_leak_suspected
+            .swap(growing, atomic::Ordering::Relaxed);
+        growing && !suspected_before
+    }
+
+    fn kill_auto_splitter_if_it_doesnt_react(&self, timer: &DebuggerTimer) {
         let Some(auto_splitter) = &*self.auto_splitter.load() else {
             return;
         };
-        if Self::try_lock(auto_splitter).is_none() {
+        let hung = Self::try_lock(auto_splitter).is_none();
+        self.interrupted_for_hang
+            .store(hung, atomic::Ordering::Relaxed);
+        if hung {
             auto_splitter.interrupt_handle().interrupt();
+            timer.0.write().unwrap().log(
+                "The auto splitter didn't finish its tick within 100 milliseconds, so it got interrupted. It is most likely stuck in an infinite loop.",
+                LogType::Runtime(LogLevel::Error),
+            );
         }
     }
 
@@ -197,38 +633,202 @@ impl SharedState {
     }
 }
 
-fn runtime_thread(shared_state: Arc<SharedState>, timer: DebuggerTimer) {
+/// The amount of consecutive failed updates after which the auto splitter gets
+/// restarted if restarting on crashes is enabled.
+const CRASH_RESTART_THRESHOLD: u32 = 5;
+/// The minimum duration between two automatic restarts.
+const CRASH_RESTART_COOLDOWN: Duration = Duration::from_secs(5);
+
+fn runtime_thread(
+    shared_state: Arc<SharedState>,
+    timer: DebuggerTimer,
+    restart_sender: mpsc::Sender<()>,
+) {
+    let _ = shared_state.runtime_thread.set(thread::current());
+    timer.0.write().unwrap().log(
+        "Auto splitter thread started.",
+        LogType::Runtime(LogLevel::Info),
+    );
+
+    loop {
+        let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| {
+            runtime_loop(&shared_state, &timer, &restart_sender)
+        })) else {
+            break;
+        };
+
+        // The panic may have happened while any of the locks were held, so
+        // they need to be usable again before anything else happens.
+        timer.0.clear_poison();
+        shared_state.clear_poison();
+        shared_state.auto_splitter.store(None);
+        *shared_state.tick_started_at.lock().unwrap() = None;
+
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Unknown panic");
+        timer.0.write().unwrap().log(
+            format!(
+                "The auto splitter thread panicked, so the auto splitter got unloaded: {message}"
+            ),
+            LogType::Runtime(LogLevel::Error),
+        );
+    }
+}
+
+fn runtime_loop(
+    shared_state: &SharedState,
+    timer: &DebuggerTimer,
+    restart_sender: &mpsc::Sender<()>,
+) {
     let mut next_tick = Instant::now();
+    let mut consecutive_errors = 0;
+    let mut last_restart: Option<Instant> = None;
+    let mut completed_first_update = false;
+    let mut process_history = HashMap::<String, SeenProcess>::new();
+    let mut last_process_collection: Option<Instant> = None;
+    let mut attached_processes = 0;
+    let mut last_tick: Option<Instant> = None;
+
     loop {
+        timer.0.write().unwrap().auto_restart_if_due();
+
         let tick_rate = {
-            if let Some(auto_splitter) = &*shared_state.auto_splitter.load() {
+            if shared_state.auto_splitter.load().is_some()
+                && shared_state.is_idling_while_unfocused()
+            {
+                last_tick = None;
+                UNFOCUSED_IDLE_RATE
+            } else if shared_state.auto_splitter.load().is_some() && !shared_state.may_update() {
+                last_tick = None;
+
+                // Check for requested steps frequently while paused.
+                std::time::Duration::from_millis(10)
+            } else if let Some(auto_splitter) = &*shared_state.auto_splitter.load() {
+                let lock_start = Instant::now();
                 let mut auto_splitter_lock = auto_splitter.lock();
                 let now = Instant::now();
+                let time_of_lock = now - lock_start;
+                if let Some(last_tick) = last_tick {
+                    *shared_state.effective_tick_rate.lock().unwrap() = now - last_tick;
+                }
+                last_tick = Some(now);
+                *shared_state.tick_started_at.lock().unwrap() = Some(now);
                 let res = auto_splitter_lock.update();
+                *shared_state.tick_started_at.lock().unwrap() = None;
+                shared_state
+                    .tick_count
+                    .fetch_add(1, atomic::Ordering::Relaxed);
                 let time_of_tick = now.elapsed();
                 let memory_usage = auto_splitter_lock.memory().len();
-                {
+                let mut time_of_processes = std::time::Duration::ZERO;
+                let process_refresh_interval =
+                    *shared_state.process_refresh_interval.lock().unwrap();
+                if last_process_collection.map_or(true, |last_process_collection| {
+                    last_process_collection.elapsed() >= process_refresh_interval
+                }) {
+                    let now = Instant::now();
+                    attached_processes = 0;
                     let mut processes = shared_state.processes.lock().unwrap();
                     processes.clear();
                     auto_splitter_lock.attached_processes().for_each(|process| {
                         use std::fmt::Write;
+                        attached_processes += 1;
                         let element = processes.push();
                         let _ = write!(element.pid, "{}", process.pid());
                         element
                             .path
                             .push_str(process.path().unwrap_or("Unnamed Process"));
+
+                        let first_seen = match process_history.get_mut(&element.pid) {
+                            Some(seen) => {
+                                if Some(seen.last_seen) != last_process_collection {
+                                    // The process got detached in between, so
+                                    // it counts as newly seen.
+                                    seen.first_seen = now;
+                                    log_process_event(timer, "attached", element);
+                                }
+                                seen.last_seen = now;
+                                seen.first_seen
+                            }
+                            None => {
+                                log_process_event(timer, "attached", element);
+                                process_history.insert(
+                                    element.pid.clone(),
+                                    SeenProcess {
+                                        path: element.path.clone(),
+                                        first_seen: now,
+                                        last_seen: now,
+                                    },
+                                );
+                                now
+                            }
+                        };
+                        element.first_seen = Some(first_seen);
+                        element.last_seen = Some(now);
+                        element.attached = true;
                     });
+
+                    process_history.retain(|_, seen| {
+                        now.duration_since(seen.last_seen) < PROCESS_HISTORY_RETENTION
+                    });
+                    for (pid, seen) in &process_history {
+                        if seen.last_seen != now {
+                            let element = processes.push();
+                            element.pid.push_str(pid);
+                            element.path.push_str(&seen.path);
+                            element.first_seen = Some(seen.first_seen);
+                            element.last_seen = Some(seen.last_seen);
+                            element.attached = false;
+                            if Some(seen.last_seen) == last_process_collection {
+                                log_process_event(timer, "detached", element);
+                            }
+                        }
+                    }
+                    last_process_collection = Some(now);
+                    time_of_processes = now.elapsed();
                 }
                 let handles = auto_splitter_lock.handles();
                 drop(auto_splitter_lock);
 
+                if let Some(profile) = &mut *shared_state.profile.lock().unwrap() {
+                    profile.record(time_of_tick, memory_usage, handles);
+                }
+
+                // Simulates a slow game by delaying the next tick.
+                let injected_delay = *shared_state.injected_delay.lock().unwrap();
+                if !injected_delay.is_zero() {
+                    thread::sleep(injected_delay);
+                }
+
                 shared_state
                     .memory_usage
                     .store(memory_usage, atomic::Ordering::Relaxed);
                 shared_state
+                    .peak_memory_usage
+                    .fetch_max(memory_usage, atomic::Ordering::Relaxed);
+                if shared_state.sample_memory_usage(memory_usage) {
+                    timer.0.write().unwrap().log(
+                        format!(
+                            "The memory usage kept growing for {} seconds. The auto splitter might be leaking memory.",
+                            MEMORY_HISTORY_CAPACITY as u64 * MEMORY_SAMPLE_INTERVAL.as_secs(),
+                        ),
+                        LogType::Runtime(LogLevel::Warning),
+                    );
+                }
+                let previous_handles = shared_state
                     .handles
-                    .store(handles, atomic::Ordering::Relaxed);
+                    .swap(handles, atomic::Ordering::Relaxed);
+                shared_state.handles_delta.store(
+                    handles as i64 - previous_handles as i64,
+                    atomic::Ordering::Relaxed,
+                );
 
+                if !shared_state
+                    .slowest_tick_frozen
+                    .load(atomic::Ordering::Relaxed)
                 {
                     let mut slowest_tick = shared_state.slowest_tick.lock().unwrap();
                     if time_of_tick > *slowest_tick {
@@ -236,31 +836,127 @@ fn runtime_thread(shared_state: Arc<SharedState>, timer: DebuggerTimer) {
                     }
                 }
 
-                *shared_state.tick_rate.lock().unwrap() = auto_splitter.tick_rate();
+                let requested_tick_rate = auto_splitter.tick_rate();
+                let previous_tick_rate = std::mem::replace(
+                    &mut *shared_state.tick_rate.lock().unwrap(),
+                    requested_tick_rate,
+                );
+                // The first tick of a newly loaded auto splitter doesn't count
+                // as a change.
+                if requested_tick_rate != previous_tick_rate
+                    && shared_state.tick_count.load(atomic::Ordering::Relaxed) > 1
+                {
+                    timer.0.write().unwrap().log(
+                        format!(
+                            "Tick rate changed to {} ({:.1} Hz).",
+                            fmt_duration(
+                                time::Duration::try_from(requested_tick_rate).unwrap_or_default()
+                            ),
+                            requested_tick_rate.as_secs_f64().recip(),
+                        ),
+                        LogType::Runtime(LogLevel::Debug),
+                    );
+                }
                 *shared_state.tick_times.lock().unwrap() += time_of_tick.as_nanos() as u64;
+                {
+                    let mut recent_ticks = shared_state.recent_ticks.lock().unwrap();
+                    if recent_ticks.len() == RECENT_TICKS_CAPACITY {
+                        recent_ticks.pop_front();
+                    }
+                    recent_ticks.push_back((SystemTime::now(), time_of_tick));
+                }
+                if time_of_tick >= *shared_state.slow_tick_threshold.lock().unwrap() {
+                    let split_index = timer.0.read().unwrap().split_index;
+                    let mut slow_ticks = shared_state.slow_ticks.lock().unwrap();
+                    if slow_ticks.len() == SLOW_TICKS_CAPACITY {
+                        slow_ticks.pop_front();
+                    }
+                    slow_ticks.push_back(SlowTick {
+                        time: SystemTime::now(),
+                        duration: time_of_tick,
+                        split_index,
+                        attached_processes,
+                    });
+                }
+                {
+                    let mut breakdowns = shared_state.recent_tick_breakdowns.lock().unwrap();
+                    if breakdowns.len() == RECENT_TICKS_CAPACITY {
+                        breakdowns.pop_front();
+                    }
+                    breakdowns.push_back(TickBreakdown {
+                        lock: time_of_lock,
+                        update: time_of_tick,
+                        processes: time_of_processes,
+                    });
+                }
                 shared_state.avg_tick_secs.store(
                     0.999 * shared_state.avg_tick_secs.load(atomic::Ordering::Relaxed)
                         + 0.001 * time_of_tick.as_secs_f64(),
                     atomic::Ordering::Relaxed,
                 );
                 if let Err(e) = res {
-                    timer.0.write().unwrap().logs.push(
-                        format!("{:?}", e.context("Failed executing the auto splitter.")).into(),
-                    )
-                };
-                auto_splitter.tick_rate()
+                    let mut timer = timer.0.write().unwrap();
+                    timer.log(
+                        format!("{:?}", e.context("Failed executing the auto splitter.")),
+                        LogType::Runtime(LogLevel::Error),
+                    );
+
+                    consecutive_errors += 1;
+                    if consecutive_errors >= CRASH_RESTART_THRESHOLD
+                        && shared_state
+                            .restart_on_crash
+                            .load(atomic::Ordering::Relaxed)
+                        && last_restart.map_or(true, |t| t.elapsed() >= CRASH_RESTART_COOLDOWN)
+                    {
+                        consecutive_errors = 0;
+                        last_restart = Some(Instant::now());
+                        timer.log(
+                            format!("The auto splitter failed {CRASH_RESTART_THRESHOLD} times in a row. Restarting it."),
+                            LogType::Runtime(LogLevel::Warning),
+                        );
+                        let _ = restart_sender.send(());
+                    }
+                } else {
+                    consecutive_errors = 0;
+                    if !completed_first_update {
+                        completed_first_update = true;
+                        timer.0.write().unwrap().log(
+                            "The first update of the auto splitter completed.",
+                            LogType::Runtime(LogLevel::Info),
+                        );
+                    }
+                }
+                shared_state
+                    .tick_rate_override
+                    .lock()
+                    .unwrap()
+                    .unwrap_or_else(|| auto_splitter.tick_rate())
             } else {
                 shared_state.processes.lock().unwrap().clear();
+                process_history.clear();
+                last_process_collection = None;
+                last_tick = None;
 
-                // Tick at 10 Hz when no runtime is loaded.
-                std::time::Duration::from_secs(1) / 10
+                *shared_state.idle_tick_rate.lock().unwrap()
             }
         };
         next_tick += tick_rate;
 
         let now = Instant::now();
-        if let Some(sleep_time) = next_tick.checked_duration_since(now) {
-            thread::sleep(sleep_time);
+        if next_tick > now {
+            // Parking instead of sleeping allows the next tick to be requested
+            // early. Parking may also end spuriously, so the remaining time is
+            // checked again afterwards.
+            while let Some(sleep_time) = next_tick.checked_duration_since(Instant::now()) {
+                if shared_state
+                    .tick_requested
+                    .swap(false, atomic::Ordering::Relaxed)
+                {
+                    next_tick = Instant::now();
+                    break;
+                }
+                thread::park_timeout(sleep_time);
+            }
         } else {
             // In this case we missed the next tick already. This likely comes
             // up when the operating system was suspended for a while. Instead
@@ -273,6 +969,9 @@ fn runtime_thread(shared_state: Arc<SharedState>, timer: DebuggerTimer) {
 
 struct Debugger {
     dock_state: DockState<Tab>,
+    /// Where each tab was last seen in the main surface, so a closed tab can
+    /// be reopened where it used to be.
+    tab_locations: HashMap<Tab, NodeIndex>,
     state: AppState,
 }
 
@@ -281,18 +980,378 @@ struct AppState {
     script_path: Option<PathBuf>,
     module_modified_time: Option<SystemTime>,
     script_modified_time: Option<SystemTime>,
+    /// The modified times of the files that changed since they got loaded,
+    /// along with when the change was first seen.
+    pending_module_change: Option<(SystemTime, Instant)>,
+    pending_script_change: Option<(SystemTime, Instant)>,
+    /// Whether changed files are only reloaded once the window regains focus.
+    reload_on_focus: bool,
+    was_focused: bool,
     optimize: bool,
+    /// Whether the user chose to optimize the WASM file. Debug mode starts
+    /// out unoptimized regardless, so this is what gets stored instead of the
+    /// effective value.
+    optimize_preference: Option<bool>,
+    debug: bool,
     open_file_dialog: Option<(FileDialog, FileDialogInfo)>,
     module: Option<CompiledAutoSplitter>,
+    /// The static information about the WASM file that is loaded.
+    module_info: Option<ModuleInfo>,
+    /// The zip archive the WASM file got extracted from.
+    archive: Option<Archive>,
+    /// The error of the most recent failed attempt at loading the auto
+    /// splitter.
+    load_error: Option<String>,
+    download_url: String,
+    show_about: bool,
+    download: Option<Download>,
     shared_state: Arc<SharedState>,
     timer: DebuggerTimer,
     runtime: livesplit_auto_splitting::Runtime,
+    restart_receiver: mpsc::Receiver<()>,
+    row_budget: RowBudget,
+    performance_plot_rect: Option<egui::Rect>,
+    plot_image_request: Option<PlotImageRequest>,
+    /// The report of a finished profile run that still needs to be saved.
+    profile_report: Option<String>,
+    settings_snapshot: Option<settings::Map>,
+    statistics_precision: DurationPrecision,
+    performance_precision: DurationPrecision,
+    show_raw_ticks: bool,
+    show_tick_breakdown: bool,
+    show_slow_ticks: bool,
+    log_filter: LogFilter,
+    log_search: String,
+    /// Whether the Main tab shows the exact game time as numbers as well.
+    show_raw_game_time: bool,
+    /// Whether the logs show the date in addition to the time of day.
+    show_log_dates: bool,
+    /// Whether consecutive identical logs are shown as a single row.
+    group_repeated_logs: bool,
+    /// The percentage range the y-axis of the Performance plot is locked to,
+    /// instead of scaling with the data.
+    pinned_y_range: Option<[f64; 2]>,
+    settings_display: SettingsMapDisplay,
+    profile_secs: u64,
+    always_on_top: bool,
+    theme: Theme,
+    zoom_factor: f32,
+    /// The size of the body text in points.
+    font_size: f32,
+    timer_hotkeys: TimerHotkeys,
+    recent_files: Vec<RecentFile>,
+    memory_view: MemoryView,
+    variable_search: String,
+    variable_search_values: bool,
+    sort_variables: bool,
+    /// The variable that is plotted in the Variables tab.
+    plotted_variable: Option<Box<str>>,
+    /// Whether the settings file next to a newly opened WASM file is applied.
+    apply_settings_file: bool,
+    config_profile_name: String,
+    config_profile_request: Option<ConfigProfileRequest>,
+    /// The keys and descriptions of the most recent non-empty set of settings
+    /// widgets.
+    settings_widgets: Vec<(Arc<str>, Arc<str>)>,
+}
+
+/// How long the modified time of a file needs to stay the same before the
+/// file gets reloaded, so files that are still being written aren't loaded.
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// How long a tick needs to run before it is shown as running, so short ticks
+/// don't make the indicator flicker.
+const BUSY_INDICATOR_DELAY: Duration = Duration::from_millis(50);
+/// How long a tick can run before it is shown as stuck.
+const STUCK_TICK_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// How often reading a WASM file that is missing or empty is retried and how
+/// long to wait in between.
+const READ_RETRIES: u32 = 3;
+const READ_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// How many of the most recently loaded files are remembered.
+const MAX_RECENT_FILES: usize = 10;
+
+/// The timer actions that can be bound to hotkeys, in the order of
+/// [`TimerHotkeys::keys_mut`].
+const TIMER_ACTIONS: [(&str, fn(&mut DebuggerTimer)); 5] = [
+    ("Start", DebuggerTimer::start),
+    ("Split", DebuggerTimer::split),
+    ("Skip Split", DebuggerTimer::skip_split),
+    ("Undo Split", DebuggerTimer::undo_split),
+    ("Reset", DebuggerTimer::reset),
+];
+
+/// The keys that are offered for the timer hotkeys.
+const HOTKEY_CHOICES: [egui::Key; 12] = [
+    egui::Key::F1,
+    egui::Key::F2,
+    egui::Key::F3,
+    egui::Key::F4,
+    egui::Key::F5,
+    egui::Key::F6,
+    egui::Key::F7,
+    egui::Key::F8,
+    egui::Key::F9,
+    egui::Key::F10,
+    egui::Key::F11,
+    egui::Key::F12,
+];
+
+/// The size of the body text in points. The other text styles are scaled
+/// along with it.
+const DEFAULT_FONT_SIZE: f32 = 1.25 * 14.0;
+const MIN_FONT_SIZE: f32 = 8.0;
+const MAX_FONT_SIZE: f32 = 40.0;
+
+/// How often the runtime thread checks for work while no auto splitter is
+/// loaded by default.
+const DEFAULT_IDLE_TICK_RATE: Duration = Duration::from_millis(100);
+
+/// The range the zoom factor of the user interface can be adjusted in.
+const MIN_ZOOM_FACTOR: f32 = 0.5;
+const MAX_ZOOM_FACTOR: f32 = 3.0;
+
+/// The largest game time that can be entered manually, both positive and
+/// negative. Larger values would overflow the duration of the game time.
+const MAX_GAME_TIME_SECS: f64 = i64::MAX as f64 / 2.0;
+
+/// How long the timer waits before starting again after a reset when it
+/// automatically restarts.
+const AUTO_RESTART_DELAY: Duration = Duration::from_secs(1);
+
+/// How long a change of the timer state stays highlighted in the Main tab.
+const TIMER_STATE_HIGHLIGHT_SECS: f32 = 1.5;
+
+/// How long a variable stays highlighted after its value changed.
+const VARIABLE_HIGHLIGHT_SECS: f32 = 0.5;
+
+/// The frame time the adaptive row budget aims to stay below.
+const FRAME_BUDGET_SECS: f32 = 1.0 / 60.0;
+const MIN_RENDERED_ROWS: usize = 100;
+const MAX_RENDERED_ROWS: usize = 1_000_000;
+
+/// Limits how many rows the Logs and Variables tabs render. In adaptive mode
+/// the limit shrinks whenever a frame takes longer than the frame budget and
+/// grows again once there is enough headroom, so the UI stays responsive even
+/// when an auto splitter produces huge amounts of logs or variables.
+struct RowBudget {
+    adaptive: bool,
+    max_rows: usize,
+}
+
+impl Default for RowBudget {
+    fn default() -> Self {
+        Self {
+            adaptive: false,
+            max_rows: 10_000,
+        }
+    }
+}
+
+impl RowBudget {
+    fn adapt(&mut self, frame_secs: f32) {
+        if frame_secs > FRAME_BUDGET_SECS {
+            self.max_rows = (self.max_rows - self.max_rows / 10).max(MIN_RENDERED_ROWS);
+        } else if frame_secs < 0.5 * FRAME_BUDGET_SECS {
+            self.max_rows = (self.max_rows + self.max_rows / 20).min(MAX_RENDERED_ROWS);
+        }
+    }
+
+    fn limit(&self) -> usize {
+        if self.adaptive {
+            self.max_rows
+        } else {
+            usize::MAX
+        }
+    }
+}
+
+/// Decides which kinds of logs are shown in the Logs tab.
+#[derive(Copy, Clone, PartialEq)]
+struct LogFilter {
+    auto_splitter_messages: bool,
+    timer: bool,
+    process: bool,
+    debug: bool,
+    info: bool,
+    warnings: bool,
+    errors: bool,
+}
+
+impl LogFilter {
+    fn matches(&self, ty: LogType) -> bool {
+        match ty {
+            LogType::AutoSplitterMessage => self.auto_splitter_messages,
+            LogType::Timer => self.timer,
+            LogType::Process => self.process,
+            LogType::Runtime(LogLevel::Debug) => self.debug,
+            LogType::Runtime(LogLevel::Info) => self.info,
+            LogType::Runtime(LogLevel::Warning) => self.warnings,
+            LogType::Runtime(LogLevel::Error) => self.errors,
+            LogType::Separator => true,
+        }
+    }
+}
+
+/// Common log filters that can be selected in one click.
+#[derive(Copy, Clone)]
+enum LogFilterPreset {
+    All,
+    ErrorsOnly,
+    AttachEvents,
+    Splits,
+}
+
+impl LogFilterPreset {
+    const ALL: [Self; 4] = [
+        Self::All,
+        Self::ErrorsOnly,
+        Self::AttachEvents,
+        Self::Splits,
+    ];
+
+    fn to_str(self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::ErrorsOnly => "Errors only",
+            Self::AttachEvents => "Attach events",
+            Self::Splits => "Splits",
+        }
+    }
+
+    fn filter(self) -> LogFilter {
+        let none = LogFilter {
+            auto_splitter_messages: false,
+            timer: false,
+            process: false,
+            debug: false,
+            info: false,
+            warnings: false,
+            errors: false,
+        };
+        match self {
+            Self::All => LogFilter {
+                auto_splitter_messages: true,
+                timer: true,
+                process: true,
+                debug: true,
+                info: true,
+                warnings: true,
+                errors: true,
+            },
+            Self::ErrorsOnly => LogFilter {
+                errors: true,
+                ..none
+            },
+            Self::AttachEvents => LogFilter {
+                process: true,
+                ..none
+            },
+            Self::Splits => LogFilter {
+                timer: true,
+                ..none
+            },
+        }
+    }
+}
+
+/// Strings in the Settings Map tab that are longer than this are truncated.
+const MAX_SETTINGS_STRING_CHARS: usize = 60;
+
+/// A copy of the memory of the auto splitter that is shown in the Memory tab.
+struct MemoryView {
+    bytes: Option<Vec<u8>>,
+    bytes_per_row: usize,
+    jump_to: String,
+    scroll_to_row: Option<usize>,
+    /// A snapshot that waits for the path it gets saved to.
+    pending_snapshot: Option<Snapshot>,
+    /// A memory dump that waits for the path it gets saved to.
+    pending_dump: Option<Vec<u8>>,
+    diff: Option<MemoryDiff>,
+}
+
+impl Default for MemoryView {
+    fn default() -> Self {
+        Self {
+            bytes: None,
+            bytes_per_row: 16,
+            jump_to: String::new(),
+            scroll_to_row: None,
+            pending_snapshot: None,
+            pending_dump: None,
+            diff: None,
+        }
+    }
+}
+
+/// The byte ranges that differ between two memories.
+struct MemoryDiff {
+    title: String,
+    old: Vec<u8>,
+    new: Vec<u8>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl MemoryDiff {
+    fn new(title: String, old: Vec<u8>, new: Vec<u8>) -> Self {
+        let ranges = snapshot::diff(&old, &new);
+        Self {
+            title,
+            old,
+            new,
+            ranges,
+        }
+    }
+}
+
+/// The amount of bytes of a changed range that are shown in a memory diff.
+const MAX_DIFF_BYTES: usize = 16;
+/// The amount of changed ranges that are listed in a memory diff.
+const MAX_DIFF_RANGES: usize = 1000;
+
+/// Controls how the values in the Settings Map tab are displayed.
+#[derive(Copy, Clone, Default)]
+struct SettingsMapDisplay {
+    /// The number of decimal places floats are rounded to, if any.
+    float_precision: Option<usize>,
 }
 
 enum FileDialogInfo {
     Wasm,
+    Validate,
     Script,
     SettingsWidget(Arc<str>),
+    ExportSettingsMap,
+    ImportSettingsMap,
+    PlotImage,
+    ExportConfig,
+    ImportConfig,
+    ExportLogs,
+    ProfileReport,
+    ExportTickTimes,
+    SaveSnapshot,
+    CompareSnapshot,
+    MemoryDump,
+    DiffDumpsBefore,
+    DiffDumpsAfter(PathBuf),
+}
+
+/// A request to apply or save a configuration profile. It is handled outside of
+/// the tabs, as the profile includes the layout of the tabs themselves.
+enum ConfigProfileRequest {
+    Apply(String),
+    Save(String),
+}
+
+/// A pending request to save the performance plot as an image. The screenshot
+/// is only requested once the file dialog is no longer visible, so it doesn't
+/// end up in the image.
+struct PlotImageRequest {
+    path: PathBuf,
+    screenshot_requested: bool,
 }
 
 struct TabViewer<'a> {
@@ -303,12 +1362,62 @@ impl egui_dock::TabViewer for TabViewer<'_> {
     type Tab = Tab;
 
     fn closeable(&mut self, _: &mut Self::Tab) -> bool {
-        false
+        true
     }
 
     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
         match tab {
             Tab::Main => {
+                if self
+                    .state
+                    .shared_state
+                    .interrupted_for_hang
+                    .load(atomic::Ordering::Relaxed)
+                {
+                    ui.label(
+                        RichText::new("⚠ The previous auto splitter hung and got interrupted.")
+                            .color(ui.visuals().error_fg_color),
+                    )
+                    .on_hover_text("Its last tick didn't finish within 100 milliseconds when it got replaced, which usually means it is stuck in an infinite loop.");
+                    ui.separator();
+                }
+                if let Some(error) = &self.state.load_error {
+                    let mut dismissed = false;
+                    CollapsingHeader::new(
+                        RichText::new("Load Error")
+                            .color(ui.visuals().error_fg_color)
+                            .strong(),
+                    )
+                    .id_source("load_error")
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        if let Some(location) = error_hint::wasm_location(error) {
+                            ui.label(RichText::new(format!("at {location}")).monospace().strong());
+                        }
+                        for (i, line) in error_hint::chain(error).into_iter().enumerate() {
+                            if i == 0 {
+                                ui.label(RichText::new(line).strong());
+                            } else {
+                                ui.label(format!("{}→ {line}", "  ".repeat(i - 1)));
+                            }
+                        }
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button("Copy")
+                                .on_hover_text("Copies the full error to the clipboard.")
+                                .clicked()
+                            {
+                                ui.output_mut(|o| o.copied_text = error.clone());
+                            }
+                            dismissed = ui.button("Dismiss").clicked();
+                        });
+                    });
+                    if dismissed {
+                        self.state.load_error = None;
+                    }
+                    ui.separator();
+                }
+
                 Grid::new("main_grid")
                     .num_columns(2)
                     .spacing([40.0, 4.0])
@@ -317,31 +1426,140 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                         ui.label("WASM File").on_hover_text("The main auto splitter file to run.");
                         ui.horizontal(|ui| {
                             if ui.button("Open").clicked() {
+                                self.state.open_wasm_file_dialog();
+                            }
+                            if ui
+                                .button("Validate")
+                                .on_hover_text("Compiles a WASM file with the current optimization setting without running it.")
+                                .clicked()
+                            {
                                 let mut dialog = FileDialog::open_file(self.state.path.clone());
                                 dialog.open();
-                                self.state.open_file_dialog = Some((dialog, FileDialogInfo::Wasm));
+                                self.state.open_file_dialog = Some((dialog, FileDialogInfo::Validate));
+                            }
+                            let mut selected_recent_file = None;
+                            ComboBox::from_id_source("recent_files")
+                                .selected_text("Recent")
+                                .show_ui(ui, |ui| {
+                                    self.state.recent_files.retain(|file| file.wasm_path.exists());
+                                    if self.state.recent_files.is_empty() {
+                                        ui.label("No recent files.");
+                                    }
+                                    for file in &self.state.recent_files {
+                                        let name = file.wasm_path.file_name().unwrap_or_default().to_string_lossy();
+                                        let mut hover_text = file.wasm_path.display().to_string();
+                                        if let Some(script_path) = &file.script_path {
+                                            hover_text.push_str(&format!("\nScript: {}", script_path.display()));
+                                        }
+                                        if ui.button(name).on_hover_text(hover_text).clicked() {
+                                            selected_recent_file = Some(file.clone());
+                                        }
+                                    }
+                                });
+                            if let Some(file) = selected_recent_file {
+                                self.state.open_recent_file(file);
                             }
                             if let Some(auto_splitter) = &*self.state.shared_state.auto_splitter.load() {
                                     if ui.button("Restart").clicked() {
                                         self.state.load(Load::Restart);
                                     }
+                                    if ui
+                                        .button("Reset + Restart")
+                                        .on_hover_text("Resets the timer and then restarts the auto splitter.")
+                                        .clicked()
+                                    {
+                                        self.state.reset_and_restart();
+                                    }
                                     if ui.button("Kill").clicked() {
                                         auto_splitter.interrupt_handle().interrupt();
                                     }
+                                    let shared_state = &self.state.shared_state;
+                                    let mut paused = shared_state.paused.load(atomic::Ordering::Relaxed);
+                                    if ui
+                                        .toggle_value(&mut paused, "Pause")
+                                        .on_hover_text("Stops calling the update function until resumed.")
+                                        .changed()
+                                    {
+                                        shared_state.pending_steps.store(0, atomic::Ordering::Relaxed);
+                                        shared_state.paused.store(paused, atomic::Ordering::Relaxed);
+                                    }
+                                    if paused
+                                        && ui
+                                            .button("Step")
+                                            .on_hover_text("Calls the update function exactly once.")
+                                            .clicked()
+                                    {
+                                        shared_state.pending_steps.fetch_add(1, atomic::Ordering::Relaxed);
+                                    }
+                            }
+                            if ui
+                                .button("Reset Everything")
+                                .on_hover_text("Clears the logs, variables and statistics and resets the timer, without unloading the auto splitter.")
+                                .clicked()
+                            {
+                                self.state.reset_everything();
+                            }
+                            if let Some(path) = &self.state.path {
+                                loaded_file_label(ui, path, self.state.module_modified_time);
+                                ui.label("👁").on_hover_text(format!(
+                                    "Watching {} for changes. The auto splitter is reloaded whenever the file is modified.",
+                                    path.display(),
+                                ));
                             }
                         });
                         ui.end_row();
 
-                        ui.label("Script File")
-                            .on_hover_text("A script file that by itself is run by the auto splitter. This is only necessary if the WASM file by itself is a script runtime.");
+                        if let Some(archive) = self.state.archive.as_mut().filter(|a| a.entries.len() > 1) {
+                            ui.label("Archive Entry")
+                                .on_hover_text("The zip archive contains several WASM files. This is the one that is loaded.");
+                            let mut selected = archive.selected.clone();
+                            ComboBox::from_id_source("archive_entry")
+                                .selected_text(&selected)
+                                .show_ui(ui, |ui| {
+                                    for entry in &archive.entries {
+                                        ui.selectable_value(&mut selected, entry.clone(), entry);
+                                    }
+                                });
+                            ui.end_row();
+                            if selected != archive.selected {
+                                archive.selected = selected;
+                                self.state.load(Load::Reload);
+                            }
+                        }
 
+                        ui.label("URL")
+                            .on_hover_text("Downloads an auto splitter into a temporary file and loads it from there.");
                         ui.horizontal(|ui| {
-                            if ui.button("Open").clicked() {
-                                let mut dialog =
-                                    FileDialog::open_file(self.state.script_path.clone());
-                                dialog.open();
-                                self.state.open_file_dialog = Some((dialog, FileDialogInfo::Script));
-                            }
+                            if let Some(download) = &self.state.download {
+                                ui.spinner();
+                                ui.label(format!("Downloading {}", download.url));
+                            } else {
+                                let response = ui.add(
+                                    egui::TextEdit::singleline(&mut self.state.download_url)
+                                        .hint_text("https://example.com/auto_splitter.wasm"),
+                                );
+                                let submitted = response.lost_focus()
+                                    && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                                let url = self.state.download_url.trim();
+                                if (ui
+                                    .add_enabled(!url.is_empty(), egui::Button::new("Download"))
+                                    .clicked()
+                                    || submitted)
+                                    && !url.is_empty()
+                                {
+                                    self.state.download = Some(Download::start(url.to_owned()));
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Script File")
+                            .on_hover_text("A script file that by itself is run by the auto splitter. This is only necessary if the WASM file by itself is a script runtime.");
+
+                        ui.horizontal(|ui| {
+                            if ui.button("Open").clicked() {
+                                self.state.open_script_file_dialog();
+                            }
                             if self.state.shared_state.auto_splitter.load().is_some() {
                                 if let Some(script_path) = &self.state.script_path {
                                     if ui.button("Reload").clicked() {
@@ -349,22 +1567,239 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                                     }
                                 }
                             }
+                            if let Some(script_path) = &self.state.script_path {
+                                loaded_file_label(ui, script_path, self.state.script_modified_time);
+                                ui.label("👁").on_hover_text(format!(
+                                    "Watching {} for changes. The auto splitter is restarted whenever the file is modified.",
+                                    script_path.display(),
+                                ));
+                            }
                         });
                         ui.end_row();
 
                         ui.label("Optimize").on_hover_text("Whether to optimize the WASM file. Don't activate this when you want to step through the source code.");
-                        if ui.checkbox(&mut self.state.optimize, "").changed() {
-                            self.state.runtime = build_runtime(self.state.optimize);
-                            self.state.load(Load::Reload);
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(&mut self.state.optimize, "").changed() {
+                                self.state.optimize_preference = Some(self.state.optimize);
+                                self.state.runtime = build_runtime(self.state.optimize);
+                                self.state.load(Load::Reload);
+                            }
+                            if let Some(info) = &self.state.module_info {
+                                if self.state.optimize && info.has_debug_info() {
+                                    ui.label(RichText::new("⚠ Stepping through the source code is unreliable.").color(ui.visuals().warn_fg_color))
+                                        .on_hover_text("The WASM file contains debug information, but the optimizations may reorder or remove code, so breakpoints and variables may not match the source code.");
+                                } else if !self.state.optimize {
+                                    ui.label(RichText::new("Ticks are slower than in a real timer.").weak())
+                                        .on_hover_text("Unoptimized code can be a lot slower, so the tick times are not representative of the performance in a real timer.");
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Auto-restart on Reset").on_hover_text(format!(
+                            "Whether to start the timer again {} seconds after the auto splitter resets it, so many runs can be simulated in a row.",
+                            AUTO_RESTART_DELAY.as_secs(),
+                        ));
+                        {
+                            let mut state = self.state.timer.0.write().unwrap();
+                            if ui.checkbox(&mut state.auto_restart, "").changed() {
+                                state.auto_restart_at = None;
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Restart on Crash").on_hover_text(format!(
+                            "Whether to automatically restart the auto splitter when it fails {CRASH_RESTART_THRESHOLD} times in a row."
+                        ));
+                        let mut restart_on_crash = self.state.shared_state.restart_on_crash.load(atomic::Ordering::Relaxed);
+                        if ui.checkbox(&mut restart_on_crash, "").changed() {
+                            self.state.shared_state.restart_on_crash.store(restart_on_crash, atomic::Ordering::Relaxed);
+                        }
+                        ui.end_row();
+
+                        ui.label("Idle When Unfocused").on_hover_text("Whether the auto splitter stops getting updated while the debugger is not focused. This saves CPU time, but the auto splitter can't follow the game while the debugger is in the background.");
+                        let mut idle_when_unfocused = self.state.shared_state.idle_when_unfocused.load(atomic::Ordering::Relaxed);
+                        if ui.checkbox(&mut idle_when_unfocused, "").changed() {
+                            self.state.shared_state.idle_when_unfocused.store(idle_when_unfocused, atomic::Ordering::Relaxed);
+                        }
+                        ui.end_row();
+
+                        ui.label("Reload on Focus").on_hover_text("Whether changes to the WASM file and the script are only reloaded once the debugger regains focus, instead of as soon as the files stop changing.");
+                        ui.checkbox(&mut self.state.reload_on_focus, "");
+                        ui.end_row();
+
+                        ui.label("Always on Top").on_hover_text("Whether to keep the debugger above all other windows.");
+                        let mut always_on_top = self.state.always_on_top;
+                        if ui.checkbox(&mut always_on_top, "").changed() {
+                            self.state.set_always_on_top(ui.ctx(), always_on_top);
+                        }
+                        ui.end_row();
+
+                        ui.label("Hotkeys").on_hover_text("The keys that control the timer while the debugger is focused, as if a real timer was attached.");
+                        CollapsingHeader::new("Timer").id_source("timer_hotkeys").show(ui, |ui| {
+                            Grid::new("timer_hotkeys_grid").num_columns(2).show(ui, |ui| {
+                                for ((name, _), key) in TIMER_ACTIONS.into_iter().zip(self.state.timer_hotkeys.keys_mut()) {
+                                    ui.label(name);
+                                    ComboBox::from_id_source(("timer_hotkey", name))
+                                        .selected_text(key.map_or("None", |key| key.name()))
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(key, None, "None");
+                                            for choice in HOTKEY_CHOICES {
+                                                ui.selectable_value(key, Some(choice), choice.name());
+                                            }
+                                        });
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                        ui.end_row();
+
+                        ui.label("Font Size").on_hover_text("The size of the text in points. Headings and small text are scaled along with it.");
+                        let mut font_size = self.state.font_size;
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut font_size)
+                                    .clamp_range(MIN_FONT_SIZE..=MAX_FONT_SIZE)
+                                    .speed(0.1)
+                                    .suffix(" pt"),
+                            )
+                            .changed()
+                        {
+                            self.state.set_font_size(ui.ctx(), font_size);
+                        }
+                        ui.end_row();
+
+                        ui.label("Theme").on_hover_text("The color scheme of the debugger.");
+                        ComboBox::from_id_source("theme")
+                            .selected_text(self.state.theme.to_str())
+                            .show_ui(ui, |ui| {
+                                for theme in Theme::ALL {
+                                    ui.selectable_value(&mut self.state.theme, theme, theme.to_str());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("Configuration").on_hover_text("Exports or imports the entire configuration of the debugger as a JSON file.");
+                        ui.horizontal(|ui| {
+                            if ui.button("Export").clicked() {
+                                let mut dialog = FileDialog::save_file(None)
+                                    .default_filename("asr-debugger-config.json");
+                                dialog.open();
+                                self.state.open_file_dialog =
+                                    Some((dialog, FileDialogInfo::ExportConfig));
+                            }
+                            if ui.button("Import").clicked() {
+                                let mut dialog = FileDialog::open_file(None);
+                                dialog.open();
+                                self.state.open_file_dialog =
+                                    Some((dialog, FileDialogInfo::ImportConfig));
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Version").on_hover_text("The version of the debugger.");
+                        ui.horizontal(|ui| {
+                            ui.label(env!("CARGO_PKG_VERSION"));
+                            if ui
+                                .button("About")
+                                .on_hover_text("Shows the versions of the debugger, the auto splitting runtime and the WASM engine.")
+                                .clicked()
+                            {
+                                self.state.show_about = true;
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Config Profile").on_hover_text(format!("Applies or saves the layout and the window settings as a named profile in {}.", config::profiles_dir().display()));
+                        ui.horizontal(|ui| {
+                            ComboBox::from_id_source("config_profile")
+                                .selected_text("Apply")
+                                .show_ui(ui, |ui| {
+                                    let names = config::profile_names();
+                                    if names.is_empty() {
+                                        ui.label("No profiles saved yet.");
+                                    }
+                                    for name in names {
+                                        if ui.button(&name).clicked() {
+                                            self.state.config_profile_request =
+                                                Some(ConfigProfileRequest::Apply(name));
+                                        }
+                                    }
+                                });
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.state.config_profile_name)
+                                    .hint_text("Name")
+                                    .desired_width(100.0),
+                            );
+                            if ui.button("Save").clicked() {
+                                self.state.config_profile_request = Some(ConfigProfileRequest::Save(
+                                    self.state.config_profile_name.clone(),
+                                ));
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Current Tick").on_hover_text(format!(
+                            "Whether the update function of the auto splitter is running right now. It turns red once it runs for longer than {} milliseconds.",
+                            STUCK_TICK_THRESHOLD.as_millis(),
+                        ));
+                        let tick_started_at = *self.state.shared_state.tick_started_at.lock().unwrap();
+                        match tick_started_at
+                            .map(|started_at| started_at.elapsed())
+                            .filter(|&elapsed| elapsed >= BUSY_INDICATOR_DELAY)
+                        {
+                            Some(elapsed) => {
+                                ui.horizontal(|ui| {
+                                    let color = if elapsed >= STUCK_TICK_THRESHOLD {
+                                        ui.visuals().error_fg_color
+                                    } else {
+                                        ui.visuals().text_color()
+                                    };
+                                    ui.add(egui::Spinner::new().color(color));
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "Running for {}",
+                                            fmt_duration_with_precision(
+                                                time::Duration::try_from(elapsed).unwrap_or_default(),
+                                                DurationPrecision::Milliseconds,
+                                            ),
+                                        ))
+                                        .color(color),
+                                    );
+                                });
+                            }
+                            None => {
+                                ui.label("Idle");
+                            }
                         }
                         ui.end_row();
 
+                        if self.state.debug {
+                            ui.label("Test Data").on_hover_text("Injects synthetic variables and logs for testing the user interface without an auto splitter.");
+                            if ui.button("Inject").clicked() {
+                                self.state.inject_test_data();
+                            }
+                            ui.end_row();
+                        }
+
                         {
                             let mut state = self.state.timer.0.write().unwrap();
 
                             ui.label("Timer State").on_hover_text("The current state of the timer.");
                             ui.horizontal(|ui| {
-                                ui.label(timer_state_to_str(state.timer_state));
+                                let mut text = RichText::new(timer_state_to_str(state.timer_state));
+                                if let Some(changed) = state.timer_state_changed {
+                                    let fade = changed.elapsed().as_secs_f32() / TIMER_STATE_HIGHLIGHT_SECS;
+                                    if fade < 1.0 {
+                                        text = text.background_color(egui::Color32::from_rgba_unmultiplied(
+                                            255,
+                                            200,
+                                            0,
+                                            (96.0 * (1.0 - fade)) as u8,
+                                        ));
+                                    }
+                                }
+                                ui.label(text);
                                 if state.timer_state == TimerState::NotRunning {
                                     if ui.button("Start").clicked() {
                                         state.start();
@@ -375,16 +1810,66 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                             });
                             ui.end_row();
 
-                            ui.label("Game Time").on_hover_text("The currently specified game time.");
-                            ui.label(fmt_duration(state.game_time));
+                            // Overriding the values only makes sense during a run,
+                            // as the timer resets them when a run starts.
+                            let editable = state.timer_state != TimerState::NotRunning;
+
+                            ui.label("Game Time").on_hover_text("The currently specified game time. It can be overridden while the timer is running.");
+                            ui.horizontal(|ui| {
+                                if editable {
+                                    let mut secs = state.game_time.as_seconds_f64();
+                                    let response = ui.add(
+                                        egui::DragValue::new(&mut secs)
+                                            .clamp_range(-MAX_GAME_TIME_SECS..=MAX_GAME_TIME_SECS)
+                                            .speed(0.1)
+                                            .suffix(" s"),
+                                    );
+                                    if response.changed() {
+                                        state.game_time = time::Duration::saturating_seconds_f64(secs);
+                                    }
+                                    if response.drag_released() || response.changed() && !response.dragged() {
+                                        let message = format!("Game time manually set to {}.", fmt_duration(state.game_time));
+                                        state.log(message, LogType::Timer);
+                                    }
+                                } else {
+                                    ui.label(fmt_duration(state.game_time));
+                                }
+                                ui.checkbox(&mut self.state.show_raw_game_time, "Raw")
+                                    .on_hover_text("Additionally shows the exact game time in seconds and in milliseconds.");
+                            });
                             ui.end_row();
 
+                            if self.state.show_raw_game_time {
+                                ui.label("Raw Game Time").on_hover_text("The exact game time in seconds and in whole milliseconds.");
+                                ui.horizontal(|ui| {
+                                    let secs = state.game_time.as_seconds_f64();
+                                    ui.label(RichText::new(format!("{secs} s")).monospace());
+                                    ui.label(RichText::new(format!("{} ms", state.game_time.whole_milliseconds())).monospace());
+                                    if ui.button("Copy").on_hover_text("Copies the game time in seconds.").clicked() {
+                                        ui.output_mut(|o| o.copied_text = secs.to_string());
+                                    }
+                                });
+                                ui.end_row();
+                            }
+
                             ui.label("Game Time State").on_hover_text("The current state of the game timer.");
                             ui.label(state.game_time_state.to_str());
                             ui.end_row();
 
-                            ui.label("Split Index").on_hover_text("The index of the current split.");
-                            ui.label(state.split_index.to_string());
+                            ui.label("Split Index").on_hover_text("The index of the current split. It can be overridden while the timer is running.");
+                            if editable {
+                                let mut split_index = state.split_index;
+                                let response = ui.add(egui::DragValue::new(&mut split_index).speed(0.1));
+                                if response.changed() {
+                                    state.split_index = split_index;
+                                }
+                                if response.drag_released() || response.changed() && !response.dragged() {
+                                    let message = format!("Split index manually set to {}.", state.split_index);
+                                    state.log(message, LogType::Timer);
+                                }
+                            } else {
+                                ui.label(state.split_index.to_string());
+                            }
                             ui.end_row();
                         }
                     });
@@ -395,121 +1880,440 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                     .spacing([40.0, 4.0])
                     .striped(true)
                     .show(ui, |ui| {
+                        let precision = self.state.statistics_precision;
+
+                        ui.label("Precision").on_hover_text(
+                            "The unit the durations on this tab are rounded to.",
+                        );
+                        duration_precision_combo_box(
+                            ui,
+                            "statistics_precision",
+                            &mut self.state.statistics_precision,
+                        );
+                        ui.end_row();
+
                         ui.label("Tick Rate").on_hover_text(
                             "The duration between individual calls to the update function.",
                         );
-                        ui.label(fmt_duration(
+                        ui.label(fmt_duration_with_precision(
                             time::Duration::try_from(
                                 *self.state.shared_state.tick_rate.lock().unwrap(),
                             )
                             .unwrap_or_default(),
+                            precision,
                         ));
                         ui.end_row();
 
-                        ui.label("Avg. Tick Time").on_hover_text(
-                            "The average duration of the execution of the update function.",
+                        ui.label("Tick Rate Override").on_hover_text(
+                            "Runs the update function at a fixed rate instead of the one requested by the auto splitter.",
+                        );
+                        ui.horizontal(|ui| {
+                            let mut tick_rate_override =
+                                self.state.shared_state.tick_rate_override.lock().unwrap();
+                            let mut enabled = tick_rate_override.is_some();
+                            if ui.checkbox(&mut enabled, "").changed() {
+                                *tick_rate_override =
+                                    enabled.then(|| std::time::Duration::from_secs(1) / 10);
+                            }
+                            if let Some(tick_rate) = &mut *tick_rate_override {
+                                let mut hertz = tick_rate.as_secs_f64().recip();
+                                if ui
+                                    .add(
+                                        egui::DragValue::new(&mut hertz)
+                                            .clamp_range(0.1..=1000.0)
+                                            .speed(0.1)
+                                            .suffix(" Hz"),
+                                    )
+                                    .changed()
+                                {
+                                    if let Ok(duration) =
+                                        std::time::Duration::try_from_secs_f64(hertz.recip())
+                                    {
+                                        *tick_rate = duration;
+                                    }
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Idle Tick Rate").on_hover_text(
+                            "How often the debugger checks for work while no auto splitter is loaded.",
+                        );
+                        {
+                            let mut idle_tick_rate = self.state.shared_state.idle_tick_rate.lock().unwrap();
+                            let mut hertz = idle_tick_rate.as_secs_f64().recip();
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut hertz)
+                                        .clamp_range(0.1..=1000.0)
+                                        .speed(0.1)
+                                        .suffix(" Hz"),
+                                )
+                                .changed()
+                            {
+                                if let Ok(duration) =
+                                    std::time::Duration::try_from_secs_f64(hertz.recip())
+                                {
+                                    *idle_tick_rate = duration;
+                                }
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Effective Tick Rate").on_hover_text(
+                            "The measured duration between the last two calls to the update function.",
+                        );
+                        let effective_tick_rate = *self.state.shared_state.effective_tick_rate.lock().unwrap();
+                        if effective_tick_rate.is_zero() {
+                            ui.label("-");
+                        } else {
+                            ui.label(format!(
+                                "{} ({:.1} Hz)",
+                                fmt_duration_with_precision(
+                                    time::Duration::try_from(effective_tick_rate).unwrap_or_default(),
+                                    precision,
+                                ),
+                                effective_tick_rate.as_secs_f64().recip(),
+                            ));
+                        }
+                        ui.end_row();
+
+                        ui.label("Ticks").on_hover_text(
+                            "The amount of calls to the update function since the auto splitter was loaded.",
                         );
-                        ui.label(fmt_duration(time::Duration::seconds_f64(
+                        ui.label(
                             self.state
                                 .shared_state
-                                .avg_tick_secs
-                                .load(atomic::Ordering::Relaxed),
-                        )));
+                                .tick_count
+                                .load(atomic::Ordering::Relaxed)
+                                .to_string(),
+                        );
+                        ui.end_row();
+
+                        ui.label("Uptime").on_hover_text(
+                            "How long the auto splitter has been loaded and the average tick rate over that time.",
+                        );
+                        match *self.state.shared_state.loaded_at.lock().unwrap() {
+                            Some(loaded_at) => {
+                                let ticks = self
+                                    .state
+                                    .shared_state
+                                    .tick_count
+                                    .load(atomic::Ordering::Relaxed);
+                                ui.label(format!(
+                                    "{} ({:.1} Hz)",
+                                    fmt_elapsed(loaded_at),
+                                    ticks as f64 / loaded_at.elapsed().as_secs_f64(),
+                                ));
+                            }
+                            None => {
+                                ui.label("-");
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Injected Delay").on_hover_text(
+                            "Simulates a slow game by sleeping for this long after every call to the update function.",
+                        );
+                        {
+                            let mut injected_delay = self.state.shared_state.injected_delay.lock().unwrap();
+                            let mut millis = injected_delay.as_millis() as u64;
+                            if ui
+                                .add(egui::DragValue::new(&mut millis).clamp_range(0..=10_000).suffix(" ms"))
+                                .changed()
+                            {
+                                *injected_delay = Duration::from_millis(millis);
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Avg. Tick Time").on_hover_text(
+                            "The average duration of the execution of the update function.",
+                        );
+                        ui.label(fmt_duration_with_precision(
+                            time::Duration::seconds_f64(
+                                self.state
+                                    .shared_state
+                                    .avg_tick_secs
+                                    .load(atomic::Ordering::Relaxed),
+                            ),
+                            precision,
+                        ));
                         ui.end_row();
 
                         ui.label("Slowest Tick").on_hover_text(
                             "The slowest duration of the execution of the update function.",
                         );
                         ui.horizontal(|ui| {
-                            ui.label(fmt_duration(
+                            ui.label(fmt_duration_with_precision(
                                 time::Duration::try_from(
                                     *self.state.shared_state.slowest_tick.lock().unwrap(),
                                 )
                                 .unwrap_or_default(),
+                                precision,
                             ));
                             if ui.button("Reset").clicked() {
                                 *self.state.shared_state.slowest_tick.lock().unwrap() =
                                     std::time::Duration::ZERO;
                             }
+                            let mut frozen = self
+                                .state
+                                .shared_state
+                                .slowest_tick_frozen
+                                .load(atomic::Ordering::Relaxed);
+                            if ui
+                                .checkbox(&mut frozen, "Freeze")
+                                .on_hover_text("Keeps the current slowest tick instead of replacing it with even slower ones.")
+                                .changed()
+                            {
+                                self.state
+                                    .shared_state
+                                    .slowest_tick_frozen
+                                    .store(frozen, atomic::Ordering::Relaxed);
+                            }
                         });
                         ui.end_row();
 
                         let handles = self.state.shared_state.handles.load(atomic::Ordering::Relaxed);
-                        ui.label("Handles").on_hover_text("The current amount of handles (processes, settings maps, setting values) used by the auto splitter.");
-                        ui.label(handles.to_string());
+                        let handles_delta = self.state.shared_state.handles_delta.load(atomic::Ordering::Relaxed);
+                        ui.label("Handles").on_hover_text("The current amount of handles (processes, settings maps, setting values) used by the auto splitter and how much it changed during the last tick.");
+                        ui.label(format!("{handles} ({handles_delta:+})"));
                         ui.end_row();
 
                         let memory_usage = self.state.shared_state.memory_usage.load(atomic::Ordering::Relaxed);
-                        ui.label("Memory").on_hover_text("The current amount of memory used by the auto splitter (stack, heap, global variables). This excludes the size of the code itself.");
+                        let peak_memory_usage = self.state.shared_state.peak_memory_usage.load(atomic::Ordering::Relaxed);
+                        ui.label("Memory").on_hover_text("The current and the peak amount of memory used by the auto splitter (stack, heap, global variables). This excludes the size of the code itself.");
                         ui.horizontal(|ui| {
-                            ui.label(
-                                byte_unit::Byte::from_u64(memory_usage as _)
-                                    .get_appropriate_unit(byte_unit::UnitType::Binary)
-                                    .to_string(),
-                            );
-                            if let Some(auto_splitter) = &*self.state.shared_state.auto_splitter.load() {
-                                if ui.button("Dump").clicked() {
-                                    if let Some(auto_splitter) = SharedState::try_lock(auto_splitter) {
-                                        let result = fs::write("memory_dump.bin", auto_splitter.memory());
-                                        if let Err(e) = result {
-                                            self.state
-                                                .timer
-                                                .0
-                                                .write()
-                                                .unwrap()
-                                                .logs
-                                                .push(format!("Failed to dump memory: {}", e).into());
-                                        }
-                                    } else {
-                                        self.state
-                                                .timer
-                                                .0
-                                                .write()
-                                                .unwrap()
-                                                .logs
-                                                .push("Timed out waiting for auto splitter.".into());
-                                    }
+                            let text = RichText::new(format!(
+                                "{} / {}",
+                                fmt_bytes(memory_usage),
+                                fmt_bytes(peak_memory_usage),
+                            ));
+                            if self.state.shared_state.memory_leak_suspected.load(atomic::Ordering::Relaxed) {
+                                ui.label(text.color(egui::Color32::RED)).on_hover_text(format!(
+                                    "The memory usage kept growing for the last {} seconds.",
+                                    MEMORY_HISTORY_CAPACITY as u64 * MEMORY_SAMPLE_INTERVAL.as_secs(),
+                                ));
+                            } else {
+                                ui.label(text);
+                            }
+                            if self.state.shared_state.auto_splitter.load().is_some() && ui.button("Dump").clicked() {
+                                if let Some(memory) = self.state.read_memory() {
+                                    self.state.memory_view.pending_dump = Some(memory);
+                                    let mut dialog = FileDialog::save_file(None)
+                                        .default_filename(self.state.memory_dump_file_name());
+                                    dialog.open();
+                                    self.state.open_file_dialog = Some((dialog, FileDialogInfo::MemoryDump));
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Profile").on_hover_text("Records the tick times, memory usage, handles and logs for a fixed duration and then asks where to save the report.");
+                        ui.horizontal(|ui| {
+                            let mut profile = self.state.shared_state.profile.lock().unwrap();
+                            if let Some(run) = &*profile {
+                                ui.label(format!("{:.1} s left", run.remaining().as_secs_f64()));
+                                if ui.button("Cancel").clicked() {
+                                    *profile = None;
                                 }
+                            } else {
+                                ui.add(
+                                    egui::DragValue::new(&mut self.state.profile_secs)
+                                        .clamp_range(1..=3600)
+                                        .suffix(" s"),
+                                );
+                                if ui.button("Start").clicked() {
+                                    let timer = self.state.timer.0.read().unwrap();
+                                    *profile = Some(ProfileRun::new(
+                                        Duration::from_secs(self.state.profile_secs),
+                                        timer.dropped_logs + timer.logs.len(),
+                                    ));
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Diagnostics").on_hover_text("Copies the statistics, the attached processes and the loaded file to the clipboard, so they can be included in bug reports.");
+                        if ui.button("Copy").clicked() {
+                            let diagnostics = self.state.diagnostics();
+                            ui.output_mut(|o| o.copied_text = diagnostics);
+                        }
+                        ui.end_row();
+
+                        ui.label("Rendered Rows").on_hover_text("The maximum amount of rows rendered in the Logs and Variables tabs. When adaptive, the limit shrinks whenever rendering a frame takes too long.");
+                        ui.horizontal(|ui| {
+                            if self.state.row_budget.adaptive {
+                                ui.label(self.state.row_budget.max_rows.to_string());
+                            } else {
+                                ui.label("All");
                             }
+                            ui.checkbox(&mut self.state.row_budget.adaptive, "Adaptive");
                         });
                         ui.end_row();
                     });
             }
             Tab::Logs => {
                 let mut scroll_to_end = false;
+                let row_limit = self.state.row_budget.limit();
+                let (mut rendered, mut total) = (0, 0);
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source("log_filter_preset")
+                        .selected_text(
+                            LogFilterPreset::ALL
+                                .into_iter()
+                                .find(|preset| preset.filter() == self.state.log_filter)
+                                .map_or("Custom", LogFilterPreset::to_str),
+                        )
+                        .show_ui(ui, |ui| {
+                            for preset in LogFilterPreset::ALL {
+                                let filter = preset.filter();
+                                ui.selectable_value(
+                                    &mut self.state.log_filter,
+                                    filter,
+                                    preset.to_str(),
+                                );
+                            }
+                        });
+                    let filter = &mut self.state.log_filter;
+                    ui.toggle_value(&mut filter.auto_splitter_messages, "Auto Splitter")
+                        .on_hover_text("Messages logged by the auto splitter itself.");
+                    ui.toggle_value(&mut filter.timer, "Timer");
+                    ui.toggle_value(&mut filter.process, "Processes");
+                    ui.toggle_value(&mut filter.debug, "Debug");
+                    ui.toggle_value(&mut filter.info, "Info");
+                    ui.toggle_value(&mut filter.warnings, "Warnings");
+                    ui.toggle_value(&mut filter.errors, "Errors");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.state.log_search)
+                            .hint_text("Search")
+                            .desired_width(150.0),
+                    );
+                });
+                let filter = self.state.log_filter;
+                let search = self.state.log_search.to_lowercase();
+                let is_visible = |log: &LogMessage| {
+                    filter.matches(log.ty)
+                        && (search.is_empty() || log.message.to_lowercase().contains(&search))
+                };
                 Grid::new("log_grid")
-                    .num_columns(1)
+                    .num_columns(2)
                     .spacing([40.0, 4.0])
                     .striped(true)
                     .show(ui, |ui| {
                         let mut timer = self.state.timer.0.write().unwrap();
-                        for log in &timer.logs {
-                            ui.label(&**log);
+                        // Consecutive identical logs are shown as a single row
+                        // with the time of the latest one.
+                        let mut rows: Vec<(&LogMessage, usize)> = Vec::new();
+                        for log in timer.logs.iter().filter(|log| is_visible(log)) {
+                            match rows.last_mut() {
+                                Some((last, count))
+                                    if self.state.group_repeated_logs
+                                        && last.ty == log.ty
+                                        && last.message == log.message =>
+                                {
+                                    *last = log;
+                                    *count += 1;
+                                }
+                                _ => rows.push((log, 1)),
+                            }
+                        }
+                        total = rows.len();
+                        let skipped = total.saturating_sub(row_limit);
+                        rendered = total - skipped;
+                        for (log, count) in rows.into_iter().skip(skipped) {
+                            ui.label(
+                                RichText::new(fmt_log_time(log.time, self.state.show_log_dates))
+                                    .weak(),
+                            );
+                            let hint = match log.ty {
+                                LogType::Runtime(LogLevel::Error) => {
+                                    error_hint::parse(&log.message)
+                                }
+                                _ => None,
+                            };
+                            ui.horizontal(|ui| {
+                                if log.ty == LogType::Separator {
+                                    ui.label(RichText::new(&*log.message).strong());
+                                } else if let Some(hint) = hint {
+                                    ui.vertical(|ui| {
+                                        ui.label(&*log.message);
+                                        ui.label(
+                                            RichText::new(format!("→ {}", hint.cause)).strong(),
+                                        );
+                                        if let Some(location) = hint.location {
+                                            ui.label(
+                                                RichText::new(format!("at {location}")).weak(),
+                                            );
+                                        }
+                                    });
+                                } else {
+                                    ui.label(&*log.message);
+                                }
+                                if count > 1 {
+                                    ui.label(RichText::new(format!("×{count}")).strong())
+                                        .on_hover_text(format!(
+                                            "This message was logged {count} times in a row."
+                                        ));
+                                }
+                            });
                             ui.end_row();
                         }
                         if timer.logs.len() != timer.last_logs_len {
+                            // Only scroll if one of the new logs is actually
+                            // visible.
+                            let mut new_logs = timer.logs.iter().skip(timer.last_logs_len);
+                            scroll_to_end = new_logs.any(is_visible);
                             timer.last_logs_len = timer.logs.len();
-                            scroll_to_end = true;
                         }
                     });
                 ui.horizontal(|ui| {
+                    if rendered != total {
+                        ui.label(format!("Showing the last {rendered} of {total} logs."));
+                    }
                     if ui.button("Clear").clicked() {
-                        self.state.timer.0.write().unwrap().logs.clear();
+                        self.state.timer.0.write().unwrap().clear_logs();
+                    }
+                    let mut max_logs = self.state.timer.0.read().unwrap().max_logs;
+                    ui.label("Limit").on_hover_text(
+                        "The maximum amount of logs that are kept. The oldest logs are dropped once it is exceeded.",
+                    );
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut max_logs)
+                                .clamp_range(MIN_MAX_LOGS..=MAX_MAX_LOGS)
+                                .speed(100),
+                        )
+                        .changed()
+                    {
+                        self.state.timer.0.write().unwrap().set_max_logs(max_logs);
+                    }
+                    ui.checkbox(&mut self.state.group_repeated_logs, "Group Repeats")
+                        .on_hover_text("Shows consecutive identical logs as a single row with a count.");
+                    ui.checkbox(&mut self.state.show_log_dates, "Show Dates")
+                        .on_hover_text("Shows the date of each log in addition to the time of day.");
+                    if ui
+                        .button("Export")
+                        .on_hover_text(
+                            "Exports all the logs as a CSV file, regardless of the filters.",
+                        )
+                        .clicked()
+                    {
+                        let mut dialog = FileDialog::save_file(None).default_filename("logs.csv");
+                        dialog.open();
+                        self.state.open_file_dialog = Some((dialog, FileDialogInfo::ExportLogs));
                     }
                     if ui.button("Save").clicked() {
                         if let Err(e) = File::create("auto_splitter_logs.txt").and_then(|mut f| {
                             for log in &self.state.timer.0.read().unwrap().logs {
-                                writeln!(f, "{log}")?;
+                                writeln!(f, "{}", log.message)?;
                             }
                             Ok(())
                         }) {
-                            self.state
-                                .timer
-                                .0
-                                .write()
-                                .unwrap()
-                                .logs
-                                .push(format!("Failed to save log file: {}", e).into());
+                            self.state.timer.0.write().unwrap().log(
+                                format!("Failed to save log file: {}", e),
+                                LogType::Runtime(LogLevel::Error),
+                            );
                         }
                     }
                 });
@@ -518,18 +2322,117 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                 }
             }
             Tab::Variables => {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.state.variable_search)
+                            .hint_text("Search")
+                            .desired_width(150.0),
+                    );
+                    ui.checkbox(&mut self.state.variable_search_values, "Values")
+                        .on_hover_text("Also searches the values of the variables.");
+                    ui.checkbox(&mut self.state.sort_variables, "Sort")
+                        .on_hover_text("Sorts the variables alphabetically instead of showing them in the order the auto splitter first set them.");
+                });
+                let search = self.state.variable_search.to_lowercase();
+                let search_values = self.state.variable_search_values;
+                let sort_variables = self.state.sort_variables;
+                let row_limit = self.state.row_budget.limit();
+                let mut total = 0;
+                let mut matches = 0;
                 Grid::new("vars_grid")
                     .num_columns(2)
                     .spacing([40.0, 4.0])
                     .striped(true)
                     .show(ui, |ui| {
                         let state = self.state.timer.0.read().unwrap();
-                        for (key, value) in &state.variables {
-                            ui.label(&**key);
-                            ui.label(&**value);
+                        total = state.variables.len();
+                        let mut variables: Vec<_> = state
+                            .variables
+                            .iter()
+                            .filter(|(key, variable)| {
+                                search.is_empty()
+                                    || key.to_lowercase().contains(&search)
+                                    || search_values
+                                        && variable.value.to_lowercase().contains(&search)
+                            })
+                            .collect();
+                        if sort_variables {
+                            variables.sort_unstable_by_key(|&(key, _)| key);
+                        }
+                        for (key, variable) in variables {
+                            matches += 1;
+                            if matches > row_limit {
+                                continue;
+                            }
+                            let copy_value = || variable.value.clone();
+                            if state.variable_history.contains_key(key) {
+                                let selected =
+                                    self.state.plotted_variable.as_deref() == Some(&**key);
+                                let response = ui
+                                    .selectable_label(selected, &**key)
+                                    .on_hover_text("Click to plot the value over time.");
+                                if response.clicked() {
+                                    self.state.plotted_variable =
+                                        if selected { None } else { Some(key.clone()) };
+                                }
+                                copy_context_menu(response, key, copy_value);
+                            } else {
+                                copy_context_menu(
+                                    ui.add(egui::Label::new(&**key).sense(egui::Sense::click())),
+                                    key,
+                                    copy_value,
+                                );
+                            }
+                            let mut text = RichText::new(&variable.value);
+                            let fade =
+                                variable.changed.elapsed().as_secs_f32() / VARIABLE_HIGHLIGHT_SECS;
+                            if fade < 1.0 {
+                                text =
+                                    text.background_color(egui::Color32::from_rgba_unmultiplied(
+                                        255,
+                                        200,
+                                        0,
+                                        (96.0 * (1.0 - fade)) as u8,
+                                    ));
+                                ui.ctx().request_repaint_after(Duration::from_millis(16));
+                            }
+                            copy_context_menu(
+                                ui.add(egui::Label::new(text).sense(egui::Sense::click())),
+                                key,
+                                copy_value,
+                            );
                             ui.end_row();
                         }
                     });
+                if !search.is_empty() {
+                    ui.label(format!("Matches {matches} of {total} variables."));
+                }
+                if matches > row_limit {
+                    ui.label(format!(
+                        "Showing the first {row_limit} of {matches} variables."
+                    ));
+                }
+
+                if let Some(key) = &self.state.plotted_variable {
+                    let state = self.state.timer.0.read().unwrap();
+                    if let Some(history) = state.variable_history.get(key) {
+                        let now = Instant::now();
+                        let points: PlotPoints = history
+                            .iter()
+                            .map(|&(time, value)| [-now.duration_since(time).as_secs_f64(), value])
+                            .collect();
+                        ui.separator();
+                        Plot::new("Variable Plot")
+                            .legend(Legend::default())
+                            .x_axis_formatter(|x, _, _| format!("{x}s"))
+                            .allow_zoom(true)
+                            .allow_drag(true)
+                            .show(ui, |plot_ui| {
+                                plot_ui.line(Line::new(points).name(&**key));
+                            });
+                        ui.ctx().request_repaint();
+                    }
+                }
             }
             Tab::SettingsGUI => {
                 if let Some(runtime) = &*self.state.shared_state.auto_splitter.load() {
@@ -658,28 +2561,190 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                     .map(|r| r.settings_map());
 
                 if let Some(settings_map) = &settings_map {
-                    render_settings_map(ui, settings_map, format_args!("map"));
+                    ui.horizontal(|ui| {
+                        let display = &mut self.state.settings_display;
+                        let mut round_floats = display.float_precision.is_some();
+                        ui.checkbox(&mut round_floats, "Round Floats").on_hover_text(
+                            "Rounds floats to a fixed number of decimal places. Hover a value to see it in full precision.",
+                        );
+                        if round_floats {
+                            let precision = display.float_precision.get_or_insert(2);
+                            ui.add(egui::DragValue::new(precision).clamp_range(0..=17).suffix(" places"));
+                        } else {
+                            display.float_precision = None;
+                        }
+                    });
+                    ui.add_space(4.0);
+
+                    render_settings_map(
+                        ui,
+                        settings_map,
+                        self.state.settings_display,
+                        format_args!("map"),
+                    );
 
                     ui.add_space(10.0);
-                    if ui.button("Clear").clicked() {
-                        if let Some(runtime) = &*self.state.shared_state.auto_splitter.load() {
-                            runtime.set_settings_map(settings::Map::new());
+                    ui.horizontal(|ui| {
+                        if ui.button("Clear").clicked() {
+                            if let Some(runtime) = &*self.state.shared_state.auto_splitter.load() {
+                                runtime.set_settings_map(settings::Map::new());
+                            }
+                        }
+                        if ui
+                            .button("Paste")
+                            .on_hover_text(
+                                "Replaces the settings map with a JSON object from the clipboard.",
+                            )
+                            .clicked()
+                        {
+                            self.state.paste_settings_map();
+                        }
+                        if ui
+                            .button("Copy as Code")
+                            .on_hover_text("Copies Rust code that builds the settings map with the asr crate, so it can be used as the defaults of the auto splitter or in a test.")
+                            .clicked()
+                        {
+                            ui.output_mut(|o| o.copied_text = settings_code::map_to_code(settings_map));
+                        }
+                        if ui
+                            .button("Save")
+                            .on_hover_text("Saves the settings map as a JSON file next to the WASM file.")
+                            .clicked()
+                        {
+                            self.state.save_settings_file(settings_map);
+                        }
+                        if ui
+                            .button("Load")
+                            .on_hover_text("Loads the settings map from the JSON file next to the WASM file.")
+                            .clicked()
+                        {
+                            self.state.load_settings_file();
+                        }
+                        if ui
+                            .button("Export JSON")
+                            .on_hover_text("Saves the settings map, including nested maps and lists, as a JSON file anywhere.")
+                            .clicked()
+                        {
+                            let mut dialog = FileDialog::save_file(self.state.settings_file_path())
+                                .default_filename("settings.json");
+                            dialog.open();
+                            self.state.open_file_dialog =
+                                Some((dialog, FileDialogInfo::ExportSettingsMap));
+                        }
+                        if ui
+                            .button("Import JSON")
+                            .on_hover_text("Replaces the settings map with the contents of a JSON file.")
+                            .clicked()
+                        {
+                            let mut dialog = FileDialog::open_file(self.state.settings_file_path());
+                            dialog.open();
+                            self.state.open_file_dialog =
+                                Some((dialog, FileDialogInfo::ImportSettingsMap));
+                        }
+                        ui.checkbox(&mut self.state.apply_settings_file, "Load on Open")
+                            .on_hover_text("Automatically loads the settings file whenever a WASM file is opened.");
+                        if ui
+                            .button("Snapshot")
+                            .on_hover_text("Remembers the current settings map, so later changes can be compared against it.")
+                            .clicked()
+                        {
+                            self.state.settings_snapshot = Some(settings_map.clone());
+                        }
+                        if self.state.settings_snapshot.is_some()
+                            && ui.button("Discard Snapshot").clicked()
+                        {
+                            self.state.settings_snapshot = None;
                         }
+                    });
+
+                    if let Some(snapshot) = &self.state.settings_snapshot {
+                        ui.add_space(10.0);
+                        CollapsingHeader::new("Changes Since Snapshot")
+                            .default_open(true)
+                            .show(ui, |ui| render_settings_diff(ui, snapshot, settings_map));
                     }
                 }
             }
             Tab::Processes => {
+                let loaded = self.state.shared_state.auto_splitter.load().is_some();
+                if ui
+                    .add_enabled(loaded, egui::Button::new("Rescan Now"))
+                    .on_hover_text("Runs the next tick right away instead of waiting for the tick rate, so the auto splitter can attach to processes sooner.")
+                    .clicked()
+                {
+                    self.state.shared_state.request_tick();
+                }
+                ui.horizontal(|ui| {
+                    ui.label("Refresh Interval").on_hover_text(
+                        "How often the attached processes are collected. Collecting them less often than every tick reduces the overhead for auto splitters with a high tick rate.",
+                    );
+                    let mut interval = self.state.shared_state.process_refresh_interval.lock().unwrap();
+                    let mut millis = interval.as_millis() as u64;
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut millis)
+                                .clamp_range(0..=5_000)
+                                .custom_formatter(|millis, _| {
+                                    if millis == 0.0 {
+                                        "Every Tick".into()
+                                    } else {
+                                        format!("{millis} ms")
+                                    }
+                                }),
+                        )
+                        .changed()
+                    {
+                        *interval = Duration::from_millis(millis);
+                    }
+                });
                 Grid::new("processes_grid")
-                    .num_columns(2)
+                    .num_columns(5)
                     .spacing([40.0, 4.0])
                     .striped(true)
                     .show(ui, |ui| {
                         ui.label(RichText::new("PID").strong().underline());
                         ui.label(RichText::new("Path").strong().underline());
+                        ui.label(RichText::new("First Seen").strong().underline())
+                            .on_hover_text("How long ago the process got attached.");
+                        ui.label(RichText::new("Last Seen").strong().underline())
+                            .on_hover_text("How long ago the process was last seen attached. Detached processes are shown for a while before they are removed.");
+                        ui.label(RichText::new("Attached For").strong().underline())
+                            .on_hover_text("How long the process has been attached without interruption. Processes that keep getting detached and attached again are easy to spot this way.");
                         ui.end_row();
                         for process in &*self.state.shared_state.processes.lock().unwrap() {
-                            ui.label(&process.pid);
-                            ui.label(&process.path);
+                            let first_seen = process
+                                .first_seen
+                                .map(|t| format!("{} ago", fmt_elapsed(t)))
+                                .unwrap_or_default();
+                            let last_seen = if process.attached {
+                                "Attached".to_string()
+                            } else {
+                                process
+                                    .last_seen
+                                    .map(|t| format!("{} ago", fmt_elapsed(t)))
+                                    .unwrap_or_default()
+                            };
+                            let attached_for = match (process.first_seen, process.last_seen) {
+                                (Some(first_seen), Some(last_seen)) => fmt_duration_with_precision(
+                                    time::Duration::try_from(last_seen - first_seen)
+                                        .unwrap_or_default(),
+                                    DurationPrecision::Milliseconds,
+                                ),
+                                _ => String::new(),
+                            };
+                            if process.attached {
+                                ui.label(&process.pid);
+                                ui.label(&process.path);
+                                ui.label(first_seen);
+                                ui.label(last_seen);
+                                ui.label(attached_for);
+                            } else {
+                                ui.label(RichText::new(&process.pid).weak());
+                                ui.label(RichText::new(&process.path).weak());
+                                ui.label(RichText::new(first_seen).weak());
+                                ui.label(RichText::new(last_seen).weak());
+                                ui.label(RichText::new(attached_for).weak());
+                            }
                             ui.end_row();
                         }
                     });
@@ -687,78 +2752,537 @@ impl egui_dock::TabViewer for TabViewer<'_> {
             Tab::Performance => {
                 let mut histogram = self.state.shared_state.tick_times.lock().unwrap();
 
-                if ui.button("Clear").clicked() {
-                    histogram.clear();
-                }
-
-                let mut right_x = 0.0;
-                let scale_y = 100.0 / histogram.len() as f64;
-
-                let chart = BarChart::new(
-                    histogram
-                        .iter_recorded()
-                        .map(|bar| {
-                            let left_x = right_x;
-                            right_x = bar.percentile();
-                            let mid_x = 0.5 * (left_x + right_x);
-                            Bar::new(mid_x, scale_y * bar.count_since_last_iteration() as f64)
-                                .name(format!(
-                                    "{}\n{:.2}th percentile",
-                                    fmt_duration(time::Duration::nanoseconds(
-                                        histogram.value_at_percentile(mid_x as _) as _,
-                                    )),
-                                    mid_x
-                                ))
-                                .width(right_x - left_x)
+                ui.horizontal(|ui| {
+                    if ui.button("Clear").clicked() {
+                        histogram.clear();
+                    }
+                    if ui.button("Save Image").clicked() {
+                        let mut dialog =
+                            FileDialog::save_file(None).default_filename("performance.png");
+                        dialog.open();
+                        self.state.open_file_dialog = Some((dialog, FileDialogInfo::PlotImage));
+                    }
+                    if ui
+                        .button("Export")
+                        .on_hover_text("Exports the recorded tick times as a CSV file.")
+                        .clicked()
+                    {
+                        let mut dialog =
+                            FileDialog::save_file(None).default_filename("tick_times.csv");
+                        dialog.open();
+                        self.state.open_file_dialog =
+                            Some((dialog, FileDialogInfo::ExportTickTimes));
+                    }
+                    duration_precision_combo_box(
+                        ui,
+                        "performance_precision",
+                        &mut self.state.performance_precision,
+                    );
+                    let mut sigfig = histogram.sigfig();
+                    ComboBox::from_id_source("histogram_sigfig")
+                        .selected_text(format!("{sigfig} Significant Figures"))
+                        .show_ui(ui, |ui| {
+                            for choice in 1..=3 {
+                                ui.selectable_value(
+                                    &mut sigfig,
+                                    choice,
+                                    format!("{choice} Significant Figures"),
+                                );
+                            }
                         })
-                        .collect(),
-                )
-                .name("Tick Time");
-
-                Plot::new("Performance Plot")
-                    .legend(Legend::default())
-                    .x_axis_formatter(|x, chars, _| {
-                        let mut text = x.to_string();
-                        if chars >= text.len() + 2 {
-                            text.push_str("th");
+                        .response
+                        .on_hover_text("The resolution of the distribution. The samples recorded so far keep their previous resolution.");
+                    if sigfig != histogram.sigfig() {
+                        let mut resized = Histogram::new(sigfig).unwrap();
+                        // The new histogram resizes itself as needed, so adding
+                        // the samples can't fail.
+                        let _ = resized.add(&*histogram);
+                        *histogram = resized;
+                    }
+                    ui.checkbox(&mut self.state.show_raw_ticks, "Raw Values")
+                        .on_hover_text(format!(
+                            "Lists the durations of the {RECENT_TICKS_CAPACITY} most recent ticks."
+                        ));
+                    ui.checkbox(&mut self.state.show_slow_ticks, "Slow Ticks")
+                        .on_hover_text(format!(
+                            "Lists the {SLOW_TICKS_CAPACITY} most recent ticks that took at least as long as the threshold, along with the split index and the amount of attached processes at that moment."
+                        ));
+                    ui.checkbox(&mut self.state.show_tick_breakdown, "Breakdown")
+                        .on_hover_text(format!(
+                            "Shows how the time of the {RECENT_TICKS_CAPACITY} most recent ticks is split between the auto splitter's update and the debugger's overhead of waiting for the lock and collecting the processes."
+                        ));
+                    let mut pin_y_axis = self.state.pinned_y_range.is_some();
+                    ui.checkbox(&mut pin_y_axis, "Pin Y Axis").on_hover_text(
+                        "Locks the y-axis to a fixed percentage range, so the shape of the distribution stays comparable over time.",
+                    );
+                    if pin_y_axis {
+                        let [min, max] = self.state.pinned_y_range.get_or_insert([0.0, 10.0]);
+                        ui.add(
+                            egui::DragValue::new(min)
+                                .clamp_range(0.0..=*max)
+                                .speed(0.1)
+                                .suffix("%"),
+                        );
+                        ui.label("to");
+                        ui.add(
+                            egui::DragValue::new(max)
+                                .clamp_range(*min..=100.0)
+                                .speed(0.1)
+                                .suffix("%"),
+                        );
+                    } else {
+                        self.state.pinned_y_range = None;
+                    }
+                });
+                let precision = self.state.performance_precision;
+                let pinned_y_range = self.state.pinned_y_range;
+
+                if self.state.show_raw_ticks {
+                    egui::ScrollArea::vertical()
+                        .id_source("raw_ticks_scroll_area")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            Grid::new("raw_ticks_grid")
+                                .num_columns(3)
+                                .spacing([40.0, 4.0])
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    ui.label(RichText::new("Time").strong().underline());
+                                    ui.label(RichText::new("Duration").strong().underline());
+                                    ui.label(RichText::new("Nanoseconds").strong().underline());
+                                    ui.end_row();
+                                    for &(time, duration) in self
+                                        .state
+                                        .shared_state
+                                        .recent_ticks
+                                        .lock()
+                                        .unwrap()
+                                        .iter()
+                                        .rev()
+                                    {
+                                        ui.label(fmt_time_of_day(time));
+                                        ui.label(fmt_duration_with_precision(
+                                            time::Duration::try_from(duration).unwrap_or_default(),
+                                            precision,
+                                        ));
+                                        ui.label(duration.as_nanos().to_string());
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+
+                if self.state.show_slow_ticks {
+                    let shared_state = &self.state.shared_state;
+                    ui.horizontal(|ui| {
+                        ui.label("Threshold");
+                        let mut threshold = shared_state.slow_tick_threshold.lock().unwrap();
+                        let mut millis = threshold.as_secs_f64() * 1e3;
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut millis)
+                                    .clamp_range(0.0..=1000.0)
+                                    .speed(0.1)
+                                    .suffix(" ms"),
+                            )
+                            .changed()
+                        {
+                            *threshold = Duration::from_secs_f64(millis / 1e3);
+                        }
+                        if ui.button("Clear").clicked() {
+                            shared_state.slow_ticks.lock().unwrap().clear();
+                        }
+                    });
+                    egui::ScrollArea::vertical()
+                        .id_source("slow_ticks_scroll_area")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            Grid::new("slow_ticks_grid")
+                                .num_columns(4)
+                                .spacing([40.0, 4.0])
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    ui.label(RichText::new("Time").strong().underline());
+                                    ui.label(RichText::new("Duration").strong().underline());
+                                    ui.label(RichText::new("Split Index").strong().underline());
+                                    ui.label(RichText::new("Processes").strong().underline())
+                                        .on_hover_text(
+                                            "The amount of processes that were attached.",
+                                        );
+                                    ui.end_row();
+                                    for slow_tick in
+                                        shared_state.slow_ticks.lock().unwrap().iter().rev()
+                                    {
+                                        ui.label(fmt_time_of_day(slow_tick.time));
+                                        ui.label(fmt_duration_with_precision(
+                                            time::Duration::try_from(slow_tick.duration)
+                                                .unwrap_or_default(),
+                                            precision,
+                                        ));
+                                        ui.label(slow_tick.split_index.to_string());
+                                        ui.label(slow_tick.attached_processes.to_string());
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+
+                if self.state.show_tick_breakdown {
+                    let breakdowns = self
+                        .state
+                        .shared_state
+                        .recent_tick_breakdowns
+                        .lock()
+                        .unwrap();
+                    let chart =
+                        |name: &str, duration: fn(&TickBreakdown) -> std::time::Duration| {
+                            BarChart::new(
+                                breakdowns
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, breakdown)| {
+                                        Bar::new(i as f64, duration(breakdown).as_secs_f64() * 1e6)
+                                            .width(1.0)
+                                    })
+                                    .collect(),
+                            )
+                            .name(name)
+                        };
+                    let update = chart("Update", |b| b.update);
+                    let lock = chart("Waiting for Lock", |b| b.lock).stack_on(&[&update]);
+                    let processes =
+                        chart("Process Collection", |b| b.processes).stack_on(&[&update, &lock]);
+                    Plot::new("Tick Breakdown Plot")
+                        .legend(Legend::default())
+                        .height(150.0)
+                        .x_axis_formatter(|_, _, _| String::new())
+                        .y_axis_formatter(|y, _, _| format!("{y}µs"))
+                        .allow_zoom(true)
+                        .allow_drag(true)
+                        .show(ui, |plot_ui| {
+                            plot_ui.bar_chart(update);
+                            plot_ui.bar_chart(lock);
+                            plot_ui.bar_chart(processes);
+                        });
+                }
+
+                if histogram.is_empty() {
+                    self.state.performance_plot_rect = None;
+                    ui.label("No samples yet.");
+                } else {
+                    let chart = BarChart::new(
+                        percentile_plot::bars(&histogram)
+                            .into_iter()
+                            .map(|bar| {
+                                Bar::new(bar.mid_x, bar.height)
+                                    .name(format!(
+                                        "{}\n{:.2}th percentile",
+                                        fmt_duration_with_precision(
+                                            time::Duration::nanoseconds(
+                                                histogram.value_at_percentile(bar.mid_x) as _,
+                                            ),
+                                            precision,
+                                        ),
+                                        bar.mid_x
+                                    ))
+                                    .width(bar.width)
+                            })
+                            .collect(),
+                    )
+                    .name("Tick Time");
+                    let mean_percentile = percentile_plot::mean_percentile(&histogram);
+
+                    // Leaves room for the header and the values of the percentile
+                    // readout below the plot.
+                    let readout_height = 2.0 * (ui.text_style_height(&TextStyle::Body) + 4.0)
+                        + ui.spacing().item_spacing.y;
+                    let plot = Plot::new("Performance Plot")
+                        .legend(Legend::default())
+                        .height((ui.available_height() - readout_height).max(100.0))
+                        .x_axis_formatter(|x, chars, _| percentile_plot::fmt_axis_label(x, chars))
+                        .y_axis_formatter(|y, _, _| format!("{y}%"))
+                        .clamp_grid(true)
+                        .allow_zoom(true)
+                        .allow_drag(true)
+                        .show(ui, |plot_ui| {
+                            if let Some([min_y, max_y]) = pinned_y_range {
+                                let bounds = plot_ui.plot_bounds();
+                                plot_ui.set_plot_bounds(PlotBounds::from_min_max(
+                                    [bounds.min()[0], min_y],
+                                    [bounds.max()[0], max_y],
+                                ));
+                            }
+                            if let Some(mean_percentile) = mean_percentile {
+                                plot_ui.vline(VLine::new(mean_percentile).name("Mean"));
+                                plot_ui.vline(VLine::new(50.0).name("Median"));
+                            }
+                            plot_ui.bar_chart(chart);
+                        });
+                    self.state.performance_plot_rect = Some(plot.response.rect);
+
+                    let readouts = [
+                        ("Minimum", histogram.min()),
+                        ("50th", histogram.value_at_percentile(50.0)),
+                        ("90th", histogram.value_at_percentile(90.0)),
+                        ("99th", histogram.value_at_percentile(99.0)),
+                        ("99.9th", histogram.value_at_percentile(99.9)),
+                        ("Maximum", histogram.max()),
+                    ];
+                    Grid::new("percentiles_grid")
+                        .num_columns(readouts.len())
+                        .spacing([40.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (name, _) in readouts {
+                                ui.label(RichText::new(name).strong().underline());
+                            }
+                            ui.end_row();
+                            for (_, nanos) in readouts {
+                                ui.label(fmt_duration_with_precision(
+                                    time::Duration::nanoseconds(nanos as _),
+                                    precision,
+                                ));
+                            }
+                            ui.end_row();
+                        });
+                }
+            }
+            Tab::Module => {
+                if let Some(info) = &self.state.module_info {
+                    let pages = |pages: u64| {
+                        format!(
+                            "{pages} pages ({})",
+                            fmt_bytes(pages.saturating_mul(module_info::PAGE_SIZE) as usize),
+                        )
+                    };
+                    Grid::new("module_grid")
+                        .num_columns(2)
+                        .spacing([40.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("Size").on_hover_text("The size of the WASM file.");
+                            ui.label(fmt_bytes(info.size));
+                            ui.end_row();
+
+                            ui.label("Memory").on_hover_text(
+                                "The initial and the maximum size of each memory of the module.",
+                            );
+                            ui.vertical(|ui| {
+                                if info.memories.is_empty() {
+                                    ui.label("None");
+                                }
+                                for memory in &info.memories {
+                                    ui.label(format!(
+                                        "{}, maximum {}",
+                                        pages(memory.initial),
+                                        memory.maximum.map_or("unlimited".into(), pages),
+                                    ));
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.label("Debug Info").on_hover_text("Whether the module contains DWARF debug information, which is needed for stepping through the source code.");
+                            ui.label(if info.has_debug_info() { "Yes" } else { "No" });
+                            ui.end_row();
+                        });
+                    for (title, names) in [
+                        ("Exported Functions", &info.exports),
+                        ("Imported Functions", &info.imports),
+                        ("Custom Sections", &info.custom_sections),
+                    ] {
+                        CollapsingHeader::new(format!("{title} ({})", names.len())).show(
+                            ui,
+                            |ui| {
+                                for name in names {
+                                    ui.label(RichText::new(name).monospace());
+                                }
+                            },
+                        );
+                    }
+                } else {
+                    ui.label("No auto splitter loaded.");
+                }
+            }
+            Tab::Memory => {
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("Refresh")
+                        .on_hover_text("Reads the current memory of the auto splitter.")
+                        .clicked()
+                    {
+                        if let Some(bytes) = self.state.read_memory() {
+                            self.state.memory_view.bytes = Some(bytes);
+                        }
+                    }
+                    if ui
+                        .button("Save Snapshot")
+                        .on_hover_text("Saves the memory of the auto splitter along with its settings and the state of the timer.")
+                        .clicked()
+                    {
+                        if let Some(snapshot) = self.state.capture_snapshot() {
+                            self.state.memory_view.pending_snapshot = Some(snapshot);
+                            let mut dialog =
+                                FileDialog::save_file(None).default_filename("snapshot.bin");
+                            dialog.open();
+                            self.state.open_file_dialog =
+                                Some((dialog, FileDialogInfo::SaveSnapshot));
                         }
-                        if chars >= text.len() + 11 {
-                            text.push_str(" percentile");
+                    }
+                    if ui
+                        .button("Compare Snapshot")
+                        .on_hover_text("Lists the bytes that changed between a snapshot and the current memory of the auto splitter.")
+                        .clicked()
+                    {
+                        let mut dialog = FileDialog::open_file(None);
+                        dialog.open();
+                        self.state.open_file_dialog =
+                            Some((dialog, FileDialogInfo::CompareSnapshot));
+                    }
+                    if ui
+                        .button("Diff Dumps")
+                        .on_hover_text("Lists the bytes that differ between two memory dumps or snapshots. First select the earlier one, then the later one.")
+                        .clicked()
+                    {
+                        let mut dialog = FileDialog::open_file(None);
+                        dialog.open();
+                        self.state.open_file_dialog =
+                            Some((dialog, FileDialogInfo::DiffDumpsBefore));
+                    }
+                    let view = &mut self.state.memory_view;
+                    ui.label("Bytes per Row");
+                    ui.add(egui::DragValue::new(&mut view.bytes_per_row).clamp_range(1..=64));
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut view.jump_to)
+                            .hint_text("Offset (hex)")
+                            .desired_width(100.0),
+                    );
+                    if ui.button("Jump").clicked()
+                        || response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                    {
+                        let offset = view.jump_to.trim().trim_start_matches("0x");
+                        match usize::from_str_radix(offset, 16) {
+                            Ok(offset) => view.scroll_to_row = Some(offset / view.bytes_per_row),
+                            Err(_) => {
+                                self.state.timer.0.write().unwrap().log(
+                                    format!("Invalid memory offset: {}", view.jump_to),
+                                    LogType::Runtime(LogLevel::Error),
+                                );
+                            }
                         }
-                        text
-                    })
-                    .y_axis_formatter(|y, _, _| format!("{y}%"))
-                    .clamp_grid(true)
-                    .allow_zoom(true)
-                    .allow_drag(true)
-                    .show(ui, |plot_ui| {
-                        plot_ui.vline(
-                            VLine::new(histogram.percentile_below(histogram.mean() as _))
-                                .name("Mean"),
+                    }
+                });
+
+                let view = &mut self.state.memory_view;
+                let mut close_diff = false;
+                if let Some(diff) = &view.diff {
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!(
+                                "{} ({} changed ranges)",
+                                diff.title,
+                                diff.ranges.len()
+                            ))
+                            .strong(),
                         );
-                        plot_ui.vline(VLine::new(50.0).name("Median"));
-                        plot_ui.bar_chart(chart);
+                        close_diff = ui.button("Close").clicked();
                     });
+                    egui::ScrollArea::vertical()
+                        .id_source("memory_diff_scroll_area")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            Grid::new("memory_diff_grid")
+                                .num_columns(3)
+                                .spacing([40.0, 4.0])
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    ui.label(RichText::new("Offset").strong().underline());
+                                    ui.label(RichText::new("Before").strong().underline());
+                                    ui.label(RichText::new("After").strong().underline());
+                                    ui.end_row();
+                                    for range in diff.ranges.iter().take(MAX_DIFF_RANGES) {
+                                        if ui
+                                            .link(
+                                                RichText::new(format!("{:08X}", range.start))
+                                                    .monospace(),
+                                            )
+                                            .on_hover_text(format!("{} bytes", range.len()))
+                                            .clicked()
+                                        {
+                                            view.scroll_to_row =
+                                                Some(range.start / view.bytes_per_row);
+                                        }
+                                        for memory in [&diff.old, &diff.new] {
+                                            let bytes =
+                                                memory.get(range.clone()).unwrap_or_default();
+                                            ui.label(
+                                                RichText::new(fmt_diff_bytes(bytes)).monospace(),
+                                            );
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+                            if let Some(remaining) = diff
+                                .ranges
+                                .len()
+                                .checked_sub(MAX_DIFF_RANGES)
+                                .filter(|&n| n > 0)
+                            {
+                                ui.weak(format!("… {remaining} more changed ranges"));
+                            }
+                        });
+                    ui.separator();
+                }
+                if close_diff {
+                    view.diff = None;
+                }
+
+                let Some(bytes) = &view.bytes else {
+                    ui.label("Press Refresh to read the memory of the auto splitter.");
+                    return;
+                };
+                ui.label(format!("{} bytes", bytes.len()));
+
+                let bytes_per_row = view.bytes_per_row;
+                let row_height = ui.text_style_height(&TextStyle::Monospace);
+                let mut scroll_area = egui::ScrollArea::both()
+                    .id_source("memory_scroll_area")
+                    .auto_shrink([false; 2]);
+                if let Some(row) = view.scroll_to_row.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(
+                        row as f32 * (row_height + ui.spacing().item_spacing.y),
+                    );
+                }
+                scroll_area.show_rows(
+                    ui,
+                    row_height,
+                    bytes.len().div_ceil(bytes_per_row),
+                    |ui, rows| {
+                        for row in rows {
+                            let offset = row * bytes_per_row;
+                            let row_bytes = &bytes[offset..bytes.len().min(offset + bytes_per_row)];
+                            ui.label(
+                                RichText::new(fmt_memory_row(offset, row_bytes, bytes_per_row))
+                                    .monospace(),
+                            );
+                        }
+                    },
+                );
             }
         }
     }
 
     fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
-        match tab {
-            Tab::Main => "Main",
-            Tab::Statistics => "Statistics",
-            Tab::Logs => "Logs",
-            Tab::Variables => "Variables",
-            Tab::SettingsGUI => "Settings GUI",
-            Tab::SettingsMap => "Settings Map",
-            Tab::Processes => "Processes",
-            Tab::Performance => "Performance",
-        }
-        .into()
+        tab.name().into()
     }
 }
 
-fn render_settings_map(ui: &mut egui::Ui, settings_map: &settings::Map, path: fmt::Arguments<'_>) {
+fn render_settings_map(
+    ui: &mut egui::Ui,
+    settings_map: &settings::Map,
+    display: SettingsMapDisplay,
+    path: fmt::Arguments<'_>,
+) {
     Grid::new(format!("settings_{path}"))
         .num_columns(2)
         .spacing([40.0, 4.0])
@@ -769,8 +3293,63 @@ fn render_settings_map(ui: &mut egui::Ui, settings_map: &settings::Map, path: fm
             ui.end_row();
 
             for (key, value) in settings_map.iter() {
-                ui.label(key);
-                render_value(value, ui, format_args!("{path}.{key}"));
+                copy_context_menu(
+                    ui.add(egui::Label::new(key).sense(egui::Sense::click())),
+                    key,
+                    || settings_json::value_to_string(value),
+                );
+                render_value(value, ui, display, format_args!("{path}.{key}"));
+                ui.end_row();
+            }
+        });
+}
+
+/// Adds a context menu to the response of a row that allows copying its key
+/// and its value to the clipboard.
+fn copy_context_menu(response: egui::Response, key: &str, value: impl FnOnce() -> String) {
+    response.context_menu(|ui| {
+        if ui.button("Copy Key").clicked() {
+            ui.output_mut(|o| o.copied_text = key.to_owned());
+            ui.close_menu();
+        }
+        if ui.button("Copy Value").clicked() {
+            ui.output_mut(|o| o.copied_text = value());
+            ui.close_menu();
+        }
+    });
+}
+
+fn render_settings_diff(ui: &mut egui::Ui, old: &settings::Map, new: &settings::Map) {
+    let differences = settings_diff::diff_maps(old, new);
+    if differences.is_empty() {
+        ui.label("No changes.");
+        return;
+    }
+
+    Grid::new("settings_diff_grid")
+        .num_columns(2)
+        .spacing([40.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label(RichText::new("Key").strong().underline());
+            ui.label(RichText::new("Change").strong().underline());
+            ui.end_row();
+
+            for (path, difference) in &differences {
+                ui.label(path);
+                ui.label(match difference {
+                    settings_diff::Difference::Added(value) => {
+                        format!("Added {}", settings_json::value_to_string(value))
+                    }
+                    settings_diff::Difference::Removed(value) => {
+                        format!("Removed {}", settings_json::value_to_string(value))
+                    }
+                    settings_diff::Difference::Changed(old, new) => format!(
+                        "{} → {}",
+                        settings_json::value_to_string(old),
+                        settings_json::value_to_string(new),
+                    ),
+                });
                 ui.end_row();
             }
         });
@@ -779,6 +3358,7 @@ fn render_settings_map(ui: &mut egui::Ui, settings_map: &settings::Map, path: fm
 fn render_settings_list(
     ui: &mut egui::Ui,
     settings_list: &settings::List,
+    display: SettingsMapDisplay,
     path: fmt::Arguments<'_>,
 ) {
     Grid::new(format!("settings_{path}"))
@@ -787,61 +3367,270 @@ fn render_settings_list(
         .striped(true)
         .show(ui, |ui| {
             for (i, value) in settings_list.iter().enumerate() {
-                render_value(value, ui, format_args!("{path}[{i}]"));
+                render_value(value, ui, display, format_args!("{path}[{i}]"));
                 ui.end_row();
             }
         });
 }
 
-fn render_value(value: &settings::Value, ui: &mut egui::Ui, path: fmt::Arguments<'_>) {
+fn render_value(
+    value: &settings::Value,
+    ui: &mut egui::Ui,
+    display: SettingsMapDisplay,
+    path: fmt::Arguments<'_>,
+) {
     match value {
-        settings::Value::Map(v) => render_settings_map(ui, v, path),
-        settings::Value::List(v) => render_settings_list(ui, v, path),
+        settings::Value::Map(v) => {
+            CollapsingHeader::new(format!("Map ({} entries)", v.len()))
+                .id_source(format!("settings_header_{path}"))
+                .show(ui, |ui| render_settings_map(ui, v, display, path));
+        }
+        settings::Value::List(v) => {
+            CollapsingHeader::new(format!("List ({} items)", v.len()))
+                .id_source(format!("settings_header_{path}"))
+                .show(ui, |ui| render_settings_list(ui, v, display, path));
+        }
         settings::Value::Bool(v) => {
             ui.label(if *v { "true" } else { "false" });
         }
         settings::Value::I64(v) => {
             ui.label(v.to_string());
         }
-        settings::Value::F64(v) => {
-            ui.label(v.to_string());
-        }
+        settings::Value::F64(v) => match display.float_precision {
+            Some(precision) => {
+                ui.label(format!("{v:.precision$}"))
+                    .on_hover_text(v.to_string());
+            }
+            None => {
+                ui.label(v.to_string());
+            }
+        },
         settings::Value::String(v) => {
-            ui.label(&**v);
+            truncated_label(ui, v);
         }
-        _ => {
-            ui.label("<Unsupported>");
+        // Values that are newer than the debugger are shown with their debug
+        // representation, so they still show up at all.
+        v => {
+            truncated_label(ui, &format!("{v:?}"))
+                .on_hover_text("The debugger doesn't know how to display this kind of value yet.");
         }
     }
 }
 
+/// Shows the text, but only up to a limited number of characters. The full
+/// text is shown when hovering it.
+fn truncated_label(ui: &mut egui::Ui, text: &str) -> egui::Response {
+    if text.chars().count() > MAX_SETTINGS_STRING_CHARS {
+        let truncated = text
+            .chars()
+            .take(MAX_SETTINGS_STRING_CHARS)
+            .collect::<String>();
+        ui.label(format!("{truncated}…")).on_hover_text(text)
+    } else {
+        ui.label(text)
+    }
+}
+
 impl App for Debugger {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut Frame) {
         ctx.request_repaint();
 
-        if let Some(path) = &self.state.path {
-            if fs::metadata(path).ok().and_then(|m| m.modified().ok())
-                > self.state.module_modified_time
-            {
-                self.state.load(Load::Reload);
+        let dark_mode = match self.state.theme {
+            Theme::Dark => true,
+            Theme::Light => false,
+            Theme::System => frame.info().system_theme != Some(eframe::Theme::Light),
+        };
+        if ctx.style().visuals.dark_mode != dark_mode {
+            ctx.set_visuals(if dark_mode {
+                Visuals::dark()
+            } else {
+                Visuals::light()
+            });
+        }
+
+        if self.state.row_budget.adaptive {
+            if let Some(cpu_usage) = frame.info().cpu_usage {
+                self.state.row_budget.adapt(cpu_usage);
             }
         }
-        if let Some(script_path) = &self.state.script_path {
-            if fs::metadata(script_path)
-                .ok()
-                .and_then(|m| m.modified().ok())
-                > self.state.script_modified_time
-            {
-                self.state.set_script_path(script_path.clone());
+
+        if self.state.restart_receiver.try_iter().count() != 0 {
+            self.state.load(Load::Restart);
+        }
+
+        if let Some(result) = self.state.download.as_ref().and_then(Download::try_finish) {
+            let url = self.state.download.take().unwrap().url;
+            match result {
+                Ok(path) => self.state.load(Load::File(path)),
+                Err(e) => {
+                    let message = format!("Failed downloading {url}: {e:?}");
+                    self.state
+                        .timer
+                        .0
+                        .write()
+                        .unwrap()
+                        .log(message.clone(), LogType::Runtime(LogLevel::Error));
+                    self.state.load_error = Some(message);
+                }
+            }
+        }
+
+        self.state.check_settings_widgets();
+
+        let finished_profile = {
+            let mut profile = self.state.shared_state.profile.lock().unwrap();
+            if profile.as_ref().is_some_and(|p| p.is_finished()) {
+                profile.take()
+            } else {
+                None
+            }
+        };
+        if let Some(profile) = finished_profile {
+            let timer = self.state.timer.0.read().unwrap();
+            self.state.profile_report = Some(profile.report(&timer.logs, timer.dropped_logs));
+        }
+        // The report waits for any other file dialog to be closed first.
+        if self.state.profile_report.is_some()
+            && !self
+                .state
+                .open_file_dialog
+                .as_ref()
+                .is_some_and(|(dialog, _)| matches!(dialog.state(), egui_file::State::Open))
+        {
+            let mut dialog = FileDialog::save_file(None).default_filename("profile_report.txt");
+            dialog.open();
+            self.state.open_file_dialog = Some((dialog, FileDialogInfo::ProfileReport));
+        }
+
+        let focused = ctx.input(|i| i.focused);
+        let regained_focus = focused && !self.state.was_focused;
+        self.state.was_focused = focused;
+        self.state
+            .shared_state
+            .window_focused
+            .store(focused, atomic::Ordering::Relaxed);
+        if regained_focus
+            && self
+                .state
+                .shared_state
+                .idle_when_unfocused
+                .load(atomic::Ordering::Relaxed)
+        {
+            // The runtime thread may still be sleeping for the idle rate.
+            self.state.shared_state.request_tick();
+        }
+        if !self.state.reload_on_focus || regained_focus {
+            // When reloading on focus, the build is already expected to be
+            // done, so there's no need to wait for the file to be stable.
+            let debounce = !self.state.reload_on_focus;
+            if let Some(path) = &self.state.path {
+                if file_changed(
+                    path,
+                    self.state.module_modified_time,
+                    &mut self.state.pending_module_change,
+                    debounce,
+                ) {
+                    self.state.load(Load::Reload);
+                }
+            }
+            if let Some(script_path) = &self.state.script_path {
+                if file_changed(
+                    script_path,
+                    self.state.script_modified_time,
+                    &mut self.state.pending_script_change,
+                    debounce,
+                ) {
+                    self.state.set_script_path(script_path.clone());
+                }
             }
         }
 
         if let Some((dialog, info)) = &mut self.state.open_file_dialog {
-            if dialog.show(ctx).selected() {
+            let dialog_state = dialog.show(ctx).state();
+            if matches!(info, FileDialogInfo::ProfileReport)
+                && !matches!(
+                    dialog_state,
+                    egui_file::State::Open | egui_file::State::Selected
+                )
+                && self.state.profile_report.take().is_some()
+            {
+                self.state.timer.0.write().unwrap().log(
+                    "The profile report was discarded.",
+                    LogType::Runtime(LogLevel::Warning),
+                );
+            }
+            if matches!(dialog_state, egui_file::State::Selected) {
                 if let Some(file) = dialog.path().map(ToOwned::to_owned) {
                     match info {
                         FileDialogInfo::Wasm => self.state.load(Load::File(file)),
+                        FileDialogInfo::Validate => self.state.validate(&file),
                         FileDialogInfo::Script => self.state.set_script_path(file),
+                        FileDialogInfo::PlotImage => {
+                            self.state.plot_image_request = Some(PlotImageRequest {
+                                path: file,
+                                screenshot_requested: false,
+                            });
+                        }
+                        FileDialogInfo::ExportLogs => self.state.export_logs(&file),
+                        FileDialogInfo::ProfileReport => {
+                            if let Some(report) = self.state.profile_report.take() {
+                                self.state.save_profile_report(&file, &report);
+                            }
+                        }
+                        FileDialogInfo::ExportTickTimes => self.state.export_tick_times(&file),
+                        FileDialogInfo::SaveSnapshot => self.state.save_snapshot(&file),
+                        FileDialogInfo::CompareSnapshot => self.state.compare_snapshot(&file),
+                        FileDialogInfo::MemoryDump => self.state.save_memory_dump(&file),
+                        FileDialogInfo::DiffDumpsBefore => {
+                            let mut dialog = FileDialog::open_file(Some(file.clone()));
+                            dialog.open();
+                            self.state.open_file_dialog =
+                                Some((dialog, FileDialogInfo::DiffDumpsAfter(file)));
+                        }
+                        FileDialogInfo::DiffDumpsAfter(before) => {
+                            let before = before.clone();
+                            self.state.diff_dumps(&before, &file);
+                        }
+                        FileDialogInfo::ExportSettingsMap => {
+                            let auto_splitter = self.state.shared_state.auto_splitter.load();
+                            if let Some(runtime) = &*auto_splitter {
+                                self.state
+                                    .export_settings_map(&file, &runtime.settings_map());
+                            }
+                        }
+                        FileDialogInfo::ImportSettingsMap => {
+                            self.state.apply_settings_map_file(&file)
+                        }
+                        FileDialogInfo::ExportConfig => {
+                            let result = self.config().export(&file);
+                            let mut timer = self.state.timer.0.write().unwrap();
+                            match result {
+                                Ok(()) => timer.log(
+                                    "Configuration exported.",
+                                    LogType::Runtime(LogLevel::Info),
+                                ),
+                                Err(e) => timer.log(
+                                    format!(
+                                        "{:?}",
+                                        e.context("Failed exporting the configuration.")
+                                    ),
+                                    LogType::Runtime(LogLevel::Error),
+                                ),
+                            }
+                        }
+                        FileDialogInfo::ImportConfig => match AppConfig::import(&file) {
+                            Ok(config) => {
+                                self.apply_config(ctx, config);
+                                self.state.timer.0.write().unwrap().log(
+                                    "Configuration imported.",
+                                    LogType::Runtime(LogLevel::Info),
+                                );
+                            }
+                            Err(e) => self.state.timer.0.write().unwrap().log(
+                                format!("{:?}", e.context("Failed importing the configuration.")),
+                                LogType::Runtime(LogLevel::Error),
+                            ),
+                        },
                         FileDialogInfo::SettingsWidget(key) => {
                             if let Some(s) = wasi_path::from_native(&file) {
                                 if let Some(runtime) =
@@ -866,126 +3655,1360 @@ impl App for Debugger {
             }
         }
 
-        let mut tab_viewer = TabViewer {
-            state: &mut self.state,
+        if let Some(request) = &mut self.state.plot_image_request {
+            if !request.screenshot_requested {
+                request.screenshot_requested = true;
+                ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+            } else if let Some(screenshot) = ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                    _ => None,
+                })
+            }) {
+                self.state
+                    .save_plot_image(&screenshot, ctx.pixels_per_point());
+            }
+        }
+
+        {
+            use egui::gui_zoom::kb_shortcuts;
+            let mut zoom_factor = self.state.zoom_factor;
+            if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_RESET)) {
+                zoom_factor = 1.0;
+            }
+            if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_IN)) {
+                zoom_factor += 0.1;
+            }
+            if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_OUT)) {
+                zoom_factor -= 0.1;
+            }
+            if zoom_factor != self.state.zoom_factor {
+                self.state.set_zoom_factor(ctx, zoom_factor);
+            }
+        }
+
+        if !ctx.wants_keyboard_input() {
+            for ((_, action), key) in TIMER_ACTIONS
+                .into_iter()
+                .zip(self.state.timer_hotkeys.keys_mut())
+            {
+                if let Some(key) = *key {
+                    if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, key)) {
+                        action(&mut self.state.timer.clone());
+                    }
+                }
+            }
+        }
+
+        for (key, tab) in TAB_SHORTCUT_KEYS.into_iter().zip(Tab::ALL) {
+            if ctx.input_mut(|i| i.consume_key(egui::Modifiers::ALT, key)) {
+                self.open_tab(tab);
+            }
+        }
+
+        let (hovered_files, dropped_files) =
+            ctx.input(|i| (i.raw.hovered_files.clone(), i.raw.dropped_files.clone()));
+        if !hovered_files.is_empty() {
+            let auto_splitter_loaded = self.state.shared_state.auto_splitter.load().is_some();
+            preview_files_being_dropped(ctx, &hovered_files, auto_splitter_loaded);
+        }
+        if !dropped_files.is_empty() {
+            self.state.load_dropped_files(dropped_files);
+        }
+
+        if let Some(request) = self.state.config_profile_request.take() {
+            self.handle_config_profile_request(ctx, request);
+        }
+
+        self.show_menu_bar(ctx);
+        self.remember_tab_locations();
+
+        let mut tab_viewer = TabViewer {
+            state: &mut self.state,
+        };
+
+        DockArea::new(&mut self.dock_state)
+            .show_window_close_buttons(false)
+            .style(Style::from_egui(ctx.style().as_ref()))
+            .show(ctx, &mut tab_viewer);
+
+        show_about_window(ctx, &mut self.state.show_about);
+    }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, &self.config());
+    }
+}
+
+impl Debugger {
+    /// Focuses the tab. If it got closed, it is reopened where it was before,
+    /// or next to the focused tab if that place no longer exists.
+    fn open_tab(&mut self, tab: Tab) {
+        if let Some((surface, node, tab)) = self.dock_state.find_tab(&tab) {
+            self.dock_state.set_active_tab((surface, node, tab));
+            self.dock_state
+                .set_focused_node_and_surface((surface, node));
+            return;
+        }
+        if let Some(&node) = self.tab_locations.get(&tab) {
+            let is_leaf = self
+                .dock_state
+                .main_surface()
+                .iter()
+                .nth(node.0)
+                .is_some_and(|node| node.is_leaf());
+            if is_leaf {
+                self.dock_state
+                    .set_focused_node_and_surface((SurfaceIndex::main(), node));
+            }
+        }
+        self.dock_state.push_to_focused_leaf(tab);
+    }
+
+    fn remember_tab_locations(&mut self) {
+        for tab in Tab::ALL {
+            if let Some((surface, node, _)) = self.dock_state.find_tab(&tab) {
+                if surface == SurfaceIndex::main() {
+                    self.tab_locations.insert(tab, node);
+                }
+            }
+        }
+    }
+
+    fn show_menu_bar(&mut self, ctx: &egui::Context) {
+        egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+            egui::menu::bar(ui, |ui| {
+                ui.menu_button("File", |ui| {
+                    if ui.button("Open…").clicked() {
+                        self.state.open_wasm_file_dialog();
+                        ui.close_menu();
+                    }
+                    if ui.button("Open Script…").clicked() {
+                        self.state.open_script_file_dialog();
+                        ui.close_menu();
+                    }
+                    ui.menu_button("Recent", |ui| {
+                        self.state
+                            .recent_files
+                            .retain(|file| file.wasm_path.exists());
+                        if self.state.recent_files.is_empty() {
+                            ui.label("No recent files.");
+                        }
+                        let mut selected_recent_file = None;
+                        for file in &self.state.recent_files {
+                            let name = file
+                                .wasm_path
+                                .file_name()
+                                .unwrap_or_default()
+                                .to_string_lossy();
+                            if ui
+                                .button(name)
+                                .on_hover_text(file.wasm_path.display().to_string())
+                                .clicked()
+                            {
+                                selected_recent_file = Some(file.clone());
+                            }
+                        }
+                        if let Some(file) = selected_recent_file {
+                            self.state.open_recent_file(file);
+                            ui.close_menu();
+                        }
+                    });
+                    ui.separator();
+                    let auto_splitter = self.state.shared_state.auto_splitter.load_full();
+                    if ui
+                        .add_enabled(self.state.path.is_some(), egui::Button::new("Reload"))
+                        .clicked()
+                    {
+                        self.state.load(Load::Reload);
+                        ui.close_menu();
+                    }
+                    if ui
+                        .add_enabled(auto_splitter.is_some(), egui::Button::new("Restart"))
+                        .clicked()
+                    {
+                        self.state.load(Load::Restart);
+                        ui.close_menu();
+                    }
+                    if ui
+                        .add_enabled(auto_splitter.is_some(), egui::Button::new("Kill"))
+                        .clicked()
+                    {
+                        if let Some(auto_splitter) = &auto_splitter {
+                            auto_splitter.interrupt_handle().interrupt();
+                        }
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button("Quit").clicked() {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                    }
+                });
+
+                ui.menu_button("View", |ui| {
+                    for tab in Tab::ALL {
+                        let location = self.dock_state.find_tab(&tab);
+                        let mut open = location.is_some();
+                        if ui.checkbox(&mut open, tab.name()).changed() {
+                            match location {
+                                Some(location) => {
+                                    self.dock_state.remove_tab(location);
+                                }
+                                None => self.open_tab(tab),
+                            }
+                        }
+                    }
+                    if ui
+                        .button("Reset Layout")
+                        .on_hover_text("Arranges the tabs the way they are arranged by default. The auto splitter keeps running.")
+                        .clicked()
+                    {
+                        self.dock_state = default_dock_state();
+                        self.tab_locations.clear();
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    ui.menu_button("Theme", |ui| {
+                        for theme in Theme::ALL {
+                            ui.radio_value(&mut self.state.theme, theme, theme.to_str());
+                        }
+                    });
+                    ui.separator();
+                    if ui.button("Zoom In").clicked() {
+                        self.state
+                            .set_zoom_factor(ctx, self.state.zoom_factor + 0.1);
+                    }
+                    if ui.button("Zoom Out").clicked() {
+                        self.state
+                            .set_zoom_factor(ctx, self.state.zoom_factor - 0.1);
+                    }
+                    if ui.button("Reset Zoom").clicked() {
+                        self.state.set_zoom_factor(ctx, 1.0);
+                    }
+                });
+
+                ui.menu_button("Help", |ui| {
+                    if ui.button("About").clicked() {
+                        self.state.show_about = true;
+                        ui.close_menu();
+                    }
+                });
+            });
+        });
+    }
+
+    fn config(&self) -> AppConfig {
+        AppConfig {
+            always_on_top: self.state.always_on_top,
+            theme: self.state.theme,
+            zoom_factor: Some(self.state.zoom_factor),
+            font_size: Some(self.state.font_size),
+            optimize: self.state.optimize_preference,
+            tick_rate_override: *self.state.shared_state.tick_rate_override.lock().unwrap(),
+            dock_layout: serde_json::to_value(&self.dock_state).ok(),
+            recent_files: self.state.recent_files.clone(),
+            apply_settings_file: self.state.apply_settings_file,
+            reload_on_focus: self.state.reload_on_focus,
+            idle_when_unfocused: self
+                .state
+                .shared_state
+                .idle_when_unfocused
+                .load(atomic::Ordering::Relaxed),
+            timer_hotkeys: self.state.timer_hotkeys,
+            max_logs: Some(self.state.timer.0.read().unwrap().max_logs),
+        }
+    }
+
+    fn apply_config(&mut self, ctx: &egui::Context, config: AppConfig) {
+        self.state.set_always_on_top(ctx, config.always_on_top);
+        self.state.theme = config.theme;
+        if let Some(zoom_factor) = config.zoom_factor {
+            self.state.set_zoom_factor(ctx, zoom_factor);
+        }
+        if let Some(font_size) = config.font_size {
+            self.state.set_font_size(ctx, font_size);
+        }
+        if let Some(optimize) = config.optimize {
+            self.state.optimize_preference = Some(optimize);
+            if optimize != self.state.optimize {
+                self.state.optimize = optimize;
+                self.state.runtime = build_runtime(optimize);
+            }
+        }
+        *self.state.shared_state.tick_rate_override.lock().unwrap() = config.tick_rate_override;
+        if let Some(layout) = config.dock_layout {
+            self.restore_dock_layout(layout);
+        }
+        self.state.recent_files = config.recent_files;
+        self.state.apply_settings_file = config.apply_settings_file;
+        self.state.reload_on_focus = config.reload_on_focus;
+        self.state
+            .shared_state
+            .idle_when_unfocused
+            .store(config.idle_when_unfocused, atomic::Ordering::Relaxed);
+        self.state.timer_hotkeys = config.timer_hotkeys;
+        if let Some(max_logs) = config.max_logs {
+            self.state.timer.0.write().unwrap().set_max_logs(max_logs);
+        }
+    }
+
+    /// Restores a layout that got serialized as JSON. If it can't be restored,
+    /// for example because it refers to tabs that no longer exist, the default
+    /// layout is used instead.
+    fn restore_dock_layout(&mut self, layout: serde_json::Value) {
+        self.dock_state = serde_json::from_value(layout).unwrap_or_else(|_| {
+            self.state.timer.0.write().unwrap().log(
+                "The saved layout can't be restored. Using the default layout instead.",
+                LogType::Runtime(LogLevel::Warning),
+            );
+            default_dock_state()
+        });
+    }
+
+    fn config_profile(&self) -> ConfigProfile {
+        ConfigProfile {
+            always_on_top: self.state.always_on_top,
+            theme: self.state.theme,
+            zoom_factor: Some(self.state.zoom_factor),
+            font_size: Some(self.state.font_size),
+            tick_rate_override: *self.state.shared_state.tick_rate_override.lock().unwrap(),
+            idle_tick_rate: Some(*self.state.shared_state.idle_tick_rate.lock().unwrap()),
+            dock_layout: serde_json::to_value(&self.dock_state).ok(),
+        }
+    }
+
+    fn apply_config_profile(&mut self, ctx: &egui::Context, profile: ConfigProfile) {
+        self.state.set_always_on_top(ctx, profile.always_on_top);
+        self.state.theme = profile.theme;
+        if let Some(zoom_factor) = profile.zoom_factor {
+            self.state.set_zoom_factor(ctx, zoom_factor);
+        }
+        if let Some(font_size) = profile.font_size {
+            self.state.set_font_size(ctx, font_size);
+        }
+        *self.state.shared_state.tick_rate_override.lock().unwrap() = profile.tick_rate_override;
+        if let Some(idle_tick_rate) = profile.idle_tick_rate {
+            *self.state.shared_state.idle_tick_rate.lock().unwrap() = idle_tick_rate;
+        }
+        if let Some(layout) = profile.dock_layout {
+            self.restore_dock_layout(layout);
+        }
+    }
+
+    fn handle_config_profile_request(
+        &mut self,
+        ctx: &egui::Context,
+        request: ConfigProfileRequest,
+    ) {
+        let result = match &request {
+            ConfigProfileRequest::Apply(name) => {
+                ConfigProfile::load(name).map(|profile| self.apply_config_profile(ctx, profile))
+            }
+            ConfigProfileRequest::Save(name) => self.config_profile().save(name),
+        };
+        let mut timer = self.state.timer.0.write().unwrap();
+        match (result, request) {
+            (Ok(()), ConfigProfileRequest::Apply(name)) => timer.log(
+                format!("Profile `{name}` applied."),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            (Ok(()), ConfigProfileRequest::Save(name)) => timer.log(
+                format!("Profile `{name}` saved."),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            (Err(e), _) => timer.log(
+                format!("{:?}", e.context("Failed handling the config profile.")),
+                LogType::Runtime(LogLevel::Error),
+            ),
+        }
+    }
+}
+
+enum Load {
+    File(PathBuf),
+    Reload,
+    Restart,
+}
+
+impl AppState {
+    fn load(&mut self, load: Load) {
+        let settings_map = if let Load::File(path) = &load {
+            self.path = Some(path.clone());
+            self.archive = None;
+            if self.apply_settings_file {
+                self.read_settings_file()
+            } else {
+                None
+            }
+        } else {
+            self.shared_state
+                .auto_splitter
+                .load()
+                .as_ref()
+                .map(|r| r.settings_map())
+        };
+
+        if let Some(path) = &self.path {
+            self.timer.0.write().unwrap().log(
+                format!(
+                    "──── {} {} ────",
+                    match load {
+                        Load::File(_) => "loaded",
+                        Load::Reload => "reloaded",
+                        Load::Restart => "restarted",
+                    },
+                    path.file_name().unwrap_or_default().to_string_lossy(),
+                ),
+                LogType::Separator,
+            );
+        }
+
+        let mut succeeded = true;
+        self.load_error = None;
+        // The timings are only logged once the auto splitter is loaded, so
+        // they follow the message that announces it.
+        let mut timings = Vec::new();
+
+        if let (Load::File(_) | Load::Reload, Some(path)) = (&load, &self.path) {
+            let data = read_wasm_file(path);
+            self.module_modified_time = fs::metadata(path).ok().and_then(|m| m.modified().ok());
+            if let (Load::Reload, Err(e)) = (&load, &data) {
+                // The file is most likely still being written by a build, so
+                // the auto splitter keeps running until the file changes again.
+                let message = format!("{e:?}");
+                let mut timer = self.timer.0.write().unwrap();
+                timer.log(message.clone(), LogType::Runtime(LogLevel::Error));
+                timer.log(
+                    "Keeping the previous auto splitter running.",
+                    LogType::Runtime(LogLevel::Warning),
+                );
+                self.load_error = Some(message);
+                return;
+            }
+
+            let data = data.and_then(|data| {
+                // Reloading an archive extracts the same entry again.
+                let entry = self.archive.take().map(|archive| archive.selected);
+                let (data, archive) = compression::decompress(data, entry.as_deref())?;
+                self.archive = archive;
+                Ok(data)
+            });
+
+            self.module_info = None;
+            self.module = match data.and_then(|data| {
+                let start = Instant::now();
+                let module = self
+                    .runtime
+                    .compile(&data)
+                    .context("Failed loading the auto splitter.")?;
+                // The module already got validated by compiling it, so
+                // parsing it is not expected to fail.
+                self.module_info = ModuleInfo::parse(&data).ok();
+                timings.push(format!(
+                    "Compiling {} {} optimizations took {}",
+                    fmt_bytes(data.len()),
+                    if self.optimize { "with" } else { "without" },
+                    fmt_load_time(start),
+                ));
+                Ok(module)
+            }) {
+                Ok(module) => Some(module),
+                Err(e) => {
+                    succeeded = false;
+                    let message = format!("{e:?}");
+                    self.timer
+                        .0
+                        .write()
+                        .unwrap()
+                        .log(message.clone(), LogType::Runtime(LogLevel::Error));
+                    self.load_error = Some(message);
+                    None
+                }
+            };
+        }
+
+        let new_auto_splitter = if let Some(module) = &self.module {
+            let start = Instant::now();
+            match module
+                .instantiate(
+                    self.timer.clone(),
+                    settings_map,
+                    self.script_path.as_deref(),
+                )
+                .context("Failed starting the auto splitter.")
+            {
+                Ok(r) => {
+                    timings.push(format!("instantiating took {}", fmt_load_time(start)));
+                    Some(Arc::new(r))
+                }
+                Err(e) => {
+                    succeeded = false;
+                    let message = format!("{e:?}");
+                    self.timer
+                        .0
+                        .write()
+                        .unwrap()
+                        .log(message.clone(), LogType::Runtime(LogLevel::Error));
+                    self.load_error = Some(message);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        self.shared_state
+            .kill_auto_splitter_if_it_doesnt_react(&self.timer);
+        *self.shared_state.loaded_at.lock().unwrap() =
+            new_auto_splitter.is_some().then(Instant::now);
+        self.shared_state
+            .tick_count
+            .store(0, atomic::Ordering::Relaxed);
+        self.shared_state.auto_splitter.store(new_auto_splitter);
+
+        self.shared_state.reset_statistics();
+
+        if succeeded {
+            if let Load::File(_) = &load {
+                self.remember_recent_file();
+            }
+        }
+
+        let mut timer = self.timer.0.write().unwrap();
+        if let Load::File(_) = &load {
+            timer.clear();
+            self.settings_widgets.clear();
+        }
+        timer.variables.clear();
+        timer.variable_history.clear();
+
+        if succeeded {
+            timer.log(
+                match load {
+                    Load::File(_) => "Auto splitter loaded.",
+                    Load::Reload => "Auto splitter reloaded.",
+                    Load::Restart => "Auto splitter restarted.",
+                },
+                LogType::Runtime(LogLevel::Info),
+            );
+        }
+        if !timings.is_empty() {
+            let mut message = timings.join(", ");
+            message[..1].make_ascii_uppercase();
+            message.push('.');
+            timer.log(message, LogType::Runtime(LogLevel::Info));
+        }
+    }
+
+    fn reset_and_restart(&mut self) {
+        {
+            let mut timer = self.timer.0.write().unwrap();
+            timer.reset();
+            timer.log("Run reset.", LogType::Timer);
+        }
+        self.load(Load::Restart);
+    }
+
+    /// Returns the debugger to a fresh state without unloading the auto
+    /// splitter.
+    fn reset_everything(&mut self) {
+        self.shared_state.reset_statistics();
+        let mut timer = self.timer.0.write().unwrap();
+        timer.reset();
+        timer.clear_logs();
+        timer.log("Everything reset.", LogType::Runtime(LogLevel::Info));
+    }
+
+    /// Summarizes the state of the auto splitter as text for bug reports.
+    fn diagnostics(&self) -> String {
+        use std::fmt::Write;
+        let shared_state = &self.shared_state;
+        let duration = |duration: std::time::Duration| {
+            fmt_duration(time::Duration::try_from(duration).unwrap_or_default())
+        };
+
+        let mut text = String::new();
+        let _ = writeln!(
+            text,
+            "WASM File: {}",
+            self.path
+                .as_ref()
+                .map_or("None".into(), |path| path.display().to_string()),
+        );
+        if let Some(modified) = self.module_modified_time {
+            let _ = writeln!(text, "Modified: {}", fmt_time_of_day(modified));
+        }
+        let _ = writeln!(text, "Optimized: {}", self.optimize);
+        let _ = writeln!(
+            text,
+            "Tick Rate: {}",
+            duration(*shared_state.tick_rate.lock().unwrap()),
+        );
+        let _ = writeln!(
+            text,
+            "Avg. Tick Time: {}",
+            fmt_duration(time::Duration::seconds_f64(
+                shared_state.avg_tick_secs.load(atomic::Ordering::Relaxed),
+            )),
+        );
+        let _ = writeln!(
+            text,
+            "Slowest Tick: {}",
+            duration(*shared_state.slowest_tick.lock().unwrap()),
+        );
+        let _ = writeln!(
+            text,
+            "Handles: {}",
+            shared_state.handles.load(atomic::Ordering::Relaxed),
+        );
+        let _ = writeln!(
+            text,
+            "Memory: {} / {}",
+            fmt_bytes(shared_state.memory_usage.load(atomic::Ordering::Relaxed)),
+            fmt_bytes(
+                shared_state
+                    .peak_memory_usage
+                    .load(atomic::Ordering::Relaxed)
+            ),
+        );
+
+        let processes = shared_state.processes.lock().unwrap();
+        let _ = writeln!(text, "Processes ({})", processes.len());
+        for process in processes.iter() {
+            let _ = writeln!(
+                text,
+                "  {} (PID {}){}",
+                process.path,
+                process.pid,
+                if process.attached { "" } else { " [detached]" },
+            );
+        }
+
+        text
+    }
+
+    fn paste_settings_map(&mut self) {
+        let result = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .context("Failed reading the clipboard.")
+            .and_then(|text| settings_json::parse_map(&text));
+
+        let mut timer = self.timer.0.write().unwrap();
+        match result {
+            Ok(settings_map) => {
+                if let Some(runtime) = &*self.shared_state.auto_splitter.load() {
+                    runtime.set_settings_map(settings_map);
+                    timer.log("Settings map pasted.", LogType::Runtime(LogLevel::Info));
+                }
+            }
+            Err(e) => timer.log(
+                format!("{:?}", e.context("Failed pasting the settings map.")),
+                LogType::Runtime(LogLevel::Error),
+            ),
+        }
+    }
+
+    /// The settings file stored next to the WASM file.
+    fn settings_file_path(&self) -> Option<PathBuf> {
+        Some(self.path.as_ref()?.with_extension("settings.json"))
+    }
+
+    /// Reads the settings file, if there is one.
+    fn read_settings_file(&self) -> Option<settings::Map> {
+        let path = self.settings_file_path()?;
+        if !path.exists() {
+            return None;
+        }
+        self.read_settings_map(&path)
+    }
+
+    fn read_settings_map(&self, path: &Path) -> Option<settings::Map> {
+        let result = fs::read_to_string(path)
+            .context("Failed reading the file.")
+            .and_then(|json| settings_json::parse_map(&json));
+        match result {
+            Ok(settings_map) => Some(settings_map),
+            Err(e) => {
+                self.timer.0.write().unwrap().log(
+                    format!(
+                        "{:?}",
+                        e.context(format!(
+                            "Failed loading the settings from {}.",
+                            path.display()
+                        ))
+                    ),
+                    LogType::Runtime(LogLevel::Error),
+                );
+                None
+            }
+        }
+    }
+
+    fn load_settings_file(&mut self) {
+        let Some(runtime) = &*self.shared_state.auto_splitter.load() else {
+            return;
+        };
+        let Some(path) = self.settings_file_path() else {
+            return;
+        };
+        if !path.exists() {
+            self.timer.0.write().unwrap().log(
+                format!("No settings file found at {}.", path.display()),
+                LogType::Runtime(LogLevel::Warning),
+            );
+            return;
+        }
+        if let Some(settings_map) = self.read_settings_map(&path) {
+            runtime.set_settings_map(settings_map);
+            self.timer
+                .0
+                .write()
+                .unwrap()
+                .log("Settings loaded.", LogType::Runtime(LogLevel::Info));
+        }
+    }
+
+    /// Applies a settings map stored as JSON at an arbitrary path to the
+    /// currently running auto splitter.
+    fn apply_settings_map_file(&mut self, path: &Path) {
+        let Some(runtime) = &*self.shared_state.auto_splitter.load() else {
+            self.timer.0.write().unwrap().log(
+                format!(
+                    "The settings from {} weren't applied, because no auto splitter is loaded.",
+                    path.display()
+                ),
+                LogType::Runtime(LogLevel::Warning),
+            );
+            return;
+        };
+        if let Some(settings_map) = self.read_settings_map(path) {
+            runtime.set_settings_map(settings_map);
+            self.timer.0.write().unwrap().log(
+                format!("Settings loaded from {}.", path.display()),
+                LogType::Runtime(LogLevel::Info),
+            );
+        }
+    }
+
+    fn save_settings_file(&mut self, settings_map: &settings::Map) {
+        let Some(path) = self.settings_file_path() else {
+            return;
+        };
+        self.export_settings_map(&path, settings_map);
+    }
+
+    fn export_settings_map(&mut self, path: &Path, settings_map: &settings::Map) {
+        let result = fs::write(path, settings_json::map_to_string_pretty(settings_map));
+        let mut timer = self.timer.0.write().unwrap();
+        match result {
+            Ok(()) => timer.log(
+                format!("Settings saved to {}.", path.display()),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            Err(e) => timer.log(
+                format!("Failed to save the settings: {}", e),
+                LogType::Runtime(LogLevel::Error),
+            ),
+        }
+    }
+
+    fn save_plot_image(&mut self, screenshot: &egui::ColorImage, pixels_per_point: f32) {
+        let Some(request) = self.plot_image_request.take() else {
+            return;
+        };
+        let result = self
+            .performance_plot_rect
+            .context("The performance plot is not visible.")
+            .and_then(|rect| {
+                let image = screenshot.region(&rect, Some(pixels_per_point));
+                image::save_buffer_with_format(
+                    &request.path,
+                    image.as_raw(),
+                    image.size[0] as _,
+                    image.size[1] as _,
+                    image::ColorType::Rgba8,
+                    image::ImageFormat::Png,
+                )
+                .context("Failed writing the image.")
+            });
+        if let Err(e) = result {
+            self.timer.0.write().unwrap().log(
+                format!("{:?}", e.context("Failed to save the performance plot.")),
+                LogType::Runtime(LogLevel::Error),
+            );
+        }
+    }
+
+    fn inject_test_data(&mut self) {
+        let mut timer = self.timer.0.write().unwrap();
+        for i in 0..20 {
+            timer.set_variable(&format!("Test Variable {i}"), &(i * i).to_string());
+        }
+        timer.set_variable("Test Position", "(12.5, -3.0, 7.25)");
+        timer.set_variable(
+            "Test Long Value",
+            "A rather long variable value that is meant to check how the grid handles overflowing text.",
+        );
+        for i in 0..10 {
+            timer.log(
+                format!("Test log message {i}."),
+                LogType::AutoSplitterMessage,
+            );
+        }
+        timer.log(
+            "A test log message\nthat spans\nmultiple lines.",
+            LogType::AutoSplitterMessage,
+        );
+    }
+
+    fn save_profile_report(&mut self, path: &Path, report: &str) {
+        let mut timer = self.timer.0.write().unwrap();
+        match fs::write(path, report) {
+            Ok(()) => timer.log(
+                format!("Profile report saved to {}.", path.display()),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            Err(e) => timer.log(
+                format!("Failed to save the profile report: {}", e),
+                LogType::Runtime(LogLevel::Error),
+            ),
+        }
+    }
+
+    fn open_wasm_file_dialog(&mut self) {
+        let mut dialog = FileDialog::open_file(self.path.clone());
+        dialog.open();
+        self.open_file_dialog = Some((dialog, FileDialogInfo::Wasm));
+    }
+
+    fn open_script_file_dialog(&mut self) {
+        let mut dialog = FileDialog::open_file(self.script_path.clone());
+        dialog.open();
+        self.open_file_dialog = Some((dialog, FileDialogInfo::Script));
+    }
+
+    fn set_font_size(&mut self, ctx: &egui::Context, font_size: f32) {
+        self.font_size = font_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
+        set_font_size(ctx, self.font_size);
+    }
+
+    fn set_zoom_factor(&mut self, ctx: &egui::Context, zoom_factor: f32) {
+        // Rounding prevents the steps from accumulating floating point errors.
+        self.zoom_factor =
+            (zoom_factor.clamp(MIN_ZOOM_FACTOR, MAX_ZOOM_FACTOR) * 10.0).round() / 10.0;
+        ctx.set_zoom_factor(self.zoom_factor);
+    }
+
+    fn set_always_on_top(&mut self, ctx: &egui::Context, always_on_top: bool) {
+        self.always_on_top = always_on_top;
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(if always_on_top {
+            egui::WindowLevel::AlwaysOnTop
+        } else {
+            egui::WindowLevel::Normal
+        }));
+    }
+
+    /// Logs the settings widgets that appeared or disappeared since the last
+    /// time the auto splitter registered its settings widgets. Auto splitters
+    /// start out without any widgets after a reload, so empty sets are ignored.
+    fn check_settings_widgets(&mut self) {
+        let Some(auto_splitter) = &*self.shared_state.auto_splitter.load() else {
+            return;
+        };
+        let widgets = auto_splitter.settings_widgets();
+        if widgets.is_empty()
+            || widgets
+                .iter()
+                .map(|w| (&w.key, &w.description))
+                .eq(self.settings_widgets.iter().map(|(k, d)| (k, d)))
+        {
+            return;
+        }
+
+        let widgets = widgets
+            .iter()
+            .map(|w| (w.key.clone(), w.description.clone()))
+            .collect::<Vec<_>>();
+
+        if !self.settings_widgets.is_empty() {
+            let mut timer = self.timer.0.write().unwrap();
+            for (key, description) in &self.settings_widgets {
+                if !widgets.iter().any(|(k, _)| k == key) {
+                    timer.log(
+                        format!("Settings widget removed: {key} ({description})."),
+                        LogType::Runtime(LogLevel::Info),
+                    );
+                }
+            }
+            for (key, description) in &widgets {
+                if !self.settings_widgets.iter().any(|(k, _)| k == key) {
+                    timer.log(
+                        format!("Settings widget added: {key} ({description})."),
+                        LogType::Runtime(LogLevel::Info),
+                    );
+                }
+            }
+        }
+
+        self.settings_widgets = widgets;
+    }
+
+    /// Compiles the WASM file to check whether it is valid, without replacing
+    /// the auto splitter that is currently loaded.
+    fn validate(&mut self, path: &Path) {
+        let result = fs::read(path)
+            .context("Failed loading the auto splitter from the file system.")
+            .and_then(|data| {
+                let (data, _) = compression::decompress(data, None)?;
+                let start = Instant::now();
+                self.runtime
+                    .compile(&data)
+                    .context("The auto splitter failed to compile.")?;
+                Ok(fmt_load_time(start))
+            });
+        let mut timer = self.timer.0.write().unwrap();
+        match result {
+            Ok(duration) => timer.log(
+                format!(
+                    "{} compiled successfully {} optimizations in {}.",
+                    path.file_name().unwrap_or_default().to_string_lossy(),
+                    if self.optimize { "with" } else { "without" },
+                    duration,
+                ),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            Err(e) => timer.log(format!("{e:?}"), LogType::Runtime(LogLevel::Error)),
+        }
+    }
+
+    fn set_script_path(&mut self, file: PathBuf) {
+        let is_reload = Some(file.as_path()) == self.script_path.as_deref();
+        self.script_modified_time = fs::metadata(&file).ok().and_then(|m| m.modified().ok());
+        self.script_path = Some(file);
+        self.remember_recent_file();
+        self.timer.0.write().unwrap().log(
+            if is_reload {
+                "Script reloaded."
+            } else {
+                "Script loaded."
+            },
+            LogType::Runtime(LogLevel::Info),
+        );
+        self.load(Load::Restart);
+    }
+
+    /// Moves the currently loaded WASM file, along with its script, to the
+    /// front of the recent files.
+    fn remember_recent_file(&mut self) {
+        let Some(wasm_path) = self.path.clone() else {
+            return;
+        };
+        self.recent_files.retain(|file| file.wasm_path != wasm_path);
+        self.recent_files.insert(
+            0,
+            RecentFile {
+                wasm_path,
+                script_path: self.script_path.clone(),
+            },
+        );
+        self.recent_files.truncate(MAX_RECENT_FILES);
+    }
+
+    /// Loads the first dropped WASM file as the auto splitter and the first
+    /// other file as its script.
+    fn load_dropped_files(&mut self, files: Vec<egui::DroppedFile>) {
+        let (wasm_files, other_files): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .filter_map(|file| file.path)
+            .partition(|path| is_wasm_file(path));
+        if let Some(path) = wasm_files.into_iter().next() {
+            self.load(Load::File(path));
+        }
+        if let Some(path) = other_files.into_iter().next() {
+            if self.shared_state.auto_splitter.load().is_some() {
+                self.set_script_path(path);
+            }
+        }
+    }
+
+    fn export_logs(&mut self, path: &Path) {
+        let result = File::create(path).and_then(|file| {
+            let mut file = io::BufWriter::new(file);
+            writeln!(file, "Time,Type,Message")?;
+            for log in &self.timer.0.read().unwrap().logs {
+                writeln!(
+                    file,
+                    "{},{},\"{}\"",
+                    fmt_log_time(log.time, true),
+                    log.ty.to_str(),
+                    log.message.replace('"', "\"\""),
+                )?;
+            }
+            file.flush()
+        });
+        if let Err(e) = result {
+            self.timer.0.write().unwrap().log(
+                format!("Failed to export the logs: {}", e),
+                LogType::Runtime(LogLevel::Error),
+            );
+        }
+    }
+
+    /// Copies the current memory of the auto splitter.
+    fn read_memory(&self) -> Option<Vec<u8>> {
+        let auto_splitter = self.shared_state.auto_splitter.load();
+        let Some(auto_splitter) = &*auto_splitter else {
+            self.timer.0.write().unwrap().log(
+                "There is no auto splitter loaded.",
+                LogType::Runtime(LogLevel::Warning),
+            );
+            return None;
         };
-
-        DockArea::new(&mut self.dock_state)
-            .show_window_close_buttons(false)
-            .style(Style::from_egui(ctx.style().as_ref()))
-            .show(ctx, &mut tab_viewer);
+        let Some(auto_splitter) = SharedState::try_lock(auto_splitter) else {
+            self.timer.0.write().unwrap().log(
+                "Timed out waiting for auto splitter.",
+                LogType::Runtime(LogLevel::Warning),
+            );
+            return None;
+        };
+        Some(auto_splitter.memory().to_vec())
     }
-}
 
-enum Load {
-    File(PathBuf),
-    Reload,
-    Restart,
-}
+    /// The default name of a memory dump, consisting of the name of the WASM
+    /// file and the current time.
+    fn memory_dump_file_name(&self) -> String {
+        let stem = self
+            .path
+            .as_ref()
+            .and_then(|path| path.file_stem())
+            .map_or("memory".into(), |stem| stem.to_string_lossy());
+        let now = time::OffsetDateTime::now_utc().to_offset(local_offset());
+        format!(
+            "{stem}_{:04}{:02}{:02}-{:02}{:02}{:02}.bin",
+            now.year(),
+            now.month() as u8,
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second(),
+        )
+    }
 
-impl AppState {
-    fn load(&mut self, load: Load) {
-        let settings_map = if let Load::File(path) = &load {
-            self.path = Some(path.clone());
-            None
-        } else {
-            self.shared_state
-                .auto_splitter
-                .load()
-                .as_ref()
-                .map(|r| r.settings_map())
+    fn save_memory_dump(&mut self, path: &Path) {
+        let Some(memory) = self.memory_view.pending_dump.take() else {
+            return;
         };
+        let result = fs::write(path, memory);
+        let mut timer = self.timer.0.write().unwrap();
+        match result {
+            Ok(()) => timer.log(
+                format!("Memory dumped to {}.", path.display()),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            Err(e) => timer.log(
+                format!("Failed to dump memory: {}", e),
+                LogType::Runtime(LogLevel::Error),
+            ),
+        }
+    }
 
-        let mut succeeded = true;
+    fn capture_snapshot(&self) -> Option<Snapshot> {
+        let memory = self.read_memory()?;
+        let settings_map = self
+            .shared_state
+            .auto_splitter
+            .load()
+            .as_ref()
+            .map(|runtime| settings_json::map_to_json(&runtime.settings_map()))
+            .unwrap_or_default();
+        let timer = self.timer.0.read().unwrap();
+        Some(Snapshot {
+            info: SnapshotInfo {
+                timer_state: timer_state_to_str(timer.timer_state).into(),
+                split_index: timer.split_index,
+                game_time_secs: timer.game_time.as_seconds_f64(),
+                settings_map,
+            },
+            memory,
+        })
+    }
 
-        if let (Load::File(_) | Load::Reload, Some(path)) = (&load, &self.path) {
-            self.module = match fs::read(path)
-                .context("Failed loading the auto splitter from the file system.")
-                .and_then(|data| {
-                    self.runtime
-                        .compile(&data)
-                        .context("Failed loading the auto splitter.")
-                }) {
-                Ok(module) => Some(module),
-                Err(e) => {
-                    succeeded = false;
-                    self.timer
-                        .0
-                        .write()
-                        .unwrap()
-                        .logs
-                        .push(format!("{e:?}").into());
-                    None
-                }
-            };
-            self.module_modified_time = fs::metadata(path).ok().and_then(|m| m.modified().ok());
+    fn save_snapshot(&mut self, path: &Path) {
+        let Some(snapshot) = self.memory_view.pending_snapshot.take() else {
+            return;
+        };
+        let result = snapshot.write(path);
+        let mut timer = self.timer.0.write().unwrap();
+        match result {
+            Ok(()) => timer.log(
+                format!("Snapshot saved to {}.", path.display()),
+                LogType::Runtime(LogLevel::Info),
+            ),
+            Err(e) => timer.log(
+                format!("{:?}", e.context("Failed saving the snapshot.")),
+                LogType::Runtime(LogLevel::Error),
+            ),
         }
+    }
 
-        let new_auto_splitter = if let Some(module) = &self.module {
-            match module
-                .instantiate(
-                    self.timer.clone(),
-                    settings_map,
-                    self.script_path.as_deref(),
-                )
-                .context("Failed starting the auto splitter.")
-            {
-                Ok(r) => Some(Arc::new(r)),
-                Err(e) => {
-                    succeeded = false;
-                    self.timer
-                        .0
-                        .write()
-                        .unwrap()
-                        .logs
-                        .push(format!("{e:?}").into());
-                    None
-                }
+    fn compare_snapshot(&mut self, path: &Path) {
+        let snapshot = match Snapshot::read(path) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.timer.0.write().unwrap().log(
+                    format!("{:?}", e.context("Failed loading the snapshot.")),
+                    LogType::Runtime(LogLevel::Error),
+                );
+                return;
             }
-        } else {
-            None
         };
+        let Some(memory) = self.read_memory() else {
+            return;
+        };
+        let info = &snapshot.info;
+        let Some(game_time) = time::Duration::checked_seconds_f64(info.game_time_secs) else {
+            self.timer.0.write().unwrap().log(
+                format!(
+                    "Failed loading the snapshot. The game time of {} seconds is invalid.",
+                    info.game_time_secs
+                ),
+                LogType::Runtime(LogLevel::Error),
+            );
+            return;
+        };
+        let title = format!(
+            "Changes since the snapshot ({}, split {}, game time {})",
+            info.timer_state,
+            info.split_index,
+            fmt_duration(game_time),
+        );
+        self.memory_view.bytes = Some(memory.clone());
+        self.memory_view.diff = Some(MemoryDiff::new(title, snapshot.memory, memory));
+    }
 
-        self.shared_state.kill_auto_splitter_if_it_doesnt_react();
-        self.shared_state.auto_splitter.store(new_auto_splitter);
-
-        *self.shared_state.slowest_tick.lock().unwrap() = std::time::Duration::ZERO;
-        self.shared_state
-            .avg_tick_secs
-            .store(0.0, atomic::Ordering::Relaxed);
-        self.shared_state.tick_times.lock().unwrap().clear();
-
-        let mut timer = self.timer.0.write().unwrap();
-        if let Load::File(_) = &load {
-            timer.clear();
+    fn diff_dumps(&mut self, before: &Path, after: &Path) {
+        let result = snapshot::read_memory(before)
+            .with_context(|| format!("Failed reading {}.", before.display()))
+            .and_then(|old| {
+                let new = snapshot::read_memory(after)
+                    .with_context(|| format!("Failed reading {}.", after.display()))?;
+                Ok((old, new))
+            });
+        match result {
+            Ok((old, new)) => {
+                let title = format!(
+                    "Changes from {} to {}",
+                    before.file_name().unwrap_or_default().to_string_lossy(),
+                    after.file_name().unwrap_or_default().to_string_lossy(),
+                );
+                self.memory_view.bytes = Some(new.clone());
+                self.memory_view.diff = Some(MemoryDiff::new(title, old, new));
+            }
+            Err(e) => self.timer.0.write().unwrap().log(
+                format!("{:?}", e.context("Failed comparing the memory dumps.")),
+                LogType::Runtime(LogLevel::Error),
+            ),
         }
-        timer.variables.clear();
+    }
 
-        if succeeded {
-            timer.logs.push(
-                match load {
-                    Load::File(_) => "Auto splitter loaded.",
-                    Load::Reload => "Auto splitter reloaded.",
-                    Load::Restart => "Auto splitter restarted.",
-                }
-                .into(),
+    fn export_tick_times(&mut self, path: &Path) {
+        // The buckets are collected first, so the runtime thread isn't blocked
+        // while the file is written.
+        let buckets: Vec<_> = self
+            .shared_state
+            .tick_times
+            .lock()
+            .unwrap()
+            .iter_recorded()
+            .map(|bucket| (bucket.value_iterated_to(), bucket.count_at_value()))
+            .collect();
+        let result = File::create(path).and_then(|file| {
+            let mut file = io::BufWriter::new(file);
+            writeln!(file, "Nanoseconds,Count")?;
+            for (nanoseconds, count) in buckets {
+                writeln!(file, "{nanoseconds},{count}")?;
+            }
+            file.flush()
+        });
+        if let Err(e) = result {
+            self.timer.0.write().unwrap().log(
+                format!("Failed to export the tick times: {}", e),
+                LogType::Runtime(LogLevel::Error),
             );
         }
     }
 
-    fn set_script_path(&mut self, file: PathBuf) {
-        let is_reload = Some(file.as_path()) == self.script_path.as_deref();
-        self.script_modified_time = fs::metadata(&file).ok().and_then(|m| m.modified().ok());
-        self.script_path = Some(file);
-        self.timer.0.write().unwrap().logs.push(
-            if is_reload {
-                "Script reloaded."
-            } else {
-                "Script loaded."
+    fn open_recent_file(&mut self, file: RecentFile) {
+        self.script_modified_time = file
+            .script_path
+            .as_ref()
+            .and_then(|path| fs::metadata(path).ok())
+            .and_then(|m| m.modified().ok());
+        self.script_path = file.script_path;
+        self.load(Load::File(file.wasm_path));
+    }
+}
+
+/// Whether the file is a WASM file or a compressed file that may contain one.
+fn is_wasm_file(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| {
+        ["wasm", "gz", "zip"]
+            .iter()
+            .any(|wasm_extension| extension.eq_ignore_ascii_case(wasm_extension))
+    })
+}
+
+/// Sets the size of the body text. The other text styles keep their size
+/// relative to it.
+fn set_font_size(ctx: &egui::Context, font_size: f32) {
+    let scale = font_size / 14.0;
+    let mut style = (*ctx.style()).clone();
+    style.text_styles = BTreeMap::from([
+        (
+            TextStyle::Small,
+            FontId::new(scale * 10.0, FontFamily::Proportional),
+        ),
+        (
+            TextStyle::Body,
+            FontId::new(scale * 14.0, FontFamily::Proportional),
+        ),
+        (
+            TextStyle::Button,
+            FontId::new(scale * 14.0, FontFamily::Proportional),
+        ),
+        (
+            TextStyle::Heading,
+            FontId::new(scale * 20.0, FontFamily::Proportional),
+        ),
+        (
+            TextStyle::Monospace,
+            FontId::new(scale * 14.0, FontFamily::Monospace),
+        ),
+    ]);
+    ctx.set_style(style);
+}
+
+/// The versions the debugger is built with, as they are useful when reporting
+/// issues.
+fn versions() -> [(&'static str, &'static str); 3] {
+    [
+        ("Debugger", env!("CARGO_PKG_VERSION")),
+        ("livesplit-auto-splitting", env!("RUNTIME_VERSION")),
+        ("Wasmtime", env!("ENGINE_VERSION")),
+    ]
+}
+
+fn show_about_window(ctx: &egui::Context, open: &mut bool) {
+    egui::Window::new("About")
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            Grid::new("about_grid")
+                .num_columns(2)
+                .spacing([40.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for (name, version) in versions() {
+                        ui.label(name);
+                        ui.label(version);
+                        ui.end_row();
+                    }
+                });
+            ui.add_space(4.0);
+            if ui.button("Copy").clicked() {
+                let text = versions()
+                    .map(|(name, version)| format!("{name}: {version}"))
+                    .join("\n");
+                ui.output_mut(|o| o.copied_text = text);
             }
-            .into(),
-        );
-        self.load(Load::Restart);
+        });
+}
+
+/// Dims the window while files are dragged over it and describes what
+/// dropping them would do.
+fn preview_files_being_dropped(
+    ctx: &egui::Context,
+    hovered_files: &[egui::HoveredFile],
+    auto_splitter_loaded: bool,
+) {
+    let has_wasm_file = hovered_files
+        .iter()
+        .any(|file| file.path.as_deref().is_some_and(is_wasm_file));
+    let has_other_file = hovered_files
+        .iter()
+        .any(|file| file.path.as_deref().is_some_and(|path| !is_wasm_file(path)));
+    let loads_script = has_other_file && (has_wasm_file || auto_splitter_loaded);
+    let text = match (has_wasm_file, loads_script) {
+        (true, true) => "Drop to load the auto splitter and its script",
+        (true, false) => "Drop to load the auto splitter",
+        (false, true) => "Drop to load the script",
+        (false, false) if auto_splitter_loaded => "Drop a WASM file or a script to load it",
+        (false, false) => "Drop a WASM file to load it",
+    };
+
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("file_drop_preview"),
+    ));
+    let screen_rect = ctx.screen_rect();
+    painter.rect_filled(screen_rect, 0.0, egui::Color32::from_black_alpha(192));
+    painter.text(
+        screen_rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        TextStyle::Heading.resolve(&ctx.style()),
+        egui::Color32::WHITE,
+    );
+}
+
+/// Reads the WASM file of the auto splitter. While it gets rebuilt, the file may
+/// briefly be missing or empty, so reading it is retried a few times before
+/// giving up.
+fn read_wasm_file(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut retries = 0;
+    loop {
+        let result = fs::read(path);
+        let incomplete = match &result {
+            Ok(data) => data.is_empty(),
+            Err(e) => e.kind() == io::ErrorKind::NotFound,
+        };
+        if !incomplete || retries == READ_RETRIES {
+            let data = result.context("Failed loading the auto splitter from the file system.")?;
+            ensure!(!data.is_empty(), "The auto splitter file is empty.");
+            return Ok(data);
+        }
+        retries += 1;
+        thread::sleep(READ_RETRY_DELAY);
+    }
+}
+
+/// Checks whether the file got modified since it got loaded at
+/// `loaded_modified_time`. If `debounce` is set, a change is only reported once
+/// the modified time has been stable for [`RELOAD_DEBOUNCE`].
+fn file_changed(
+    path: &Path,
+    loaded_modified_time: Option<SystemTime>,
+    pending_change: &mut Option<(SystemTime, Instant)>,
+    debounce: bool,
+) -> bool {
+    let Some(modified) = fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .filter(|&modified| Some(modified) > loaded_modified_time)
+    else {
+        *pending_change = None;
+        return false;
+    };
+    if !debounce {
+        return true;
+    }
+    match pending_change {
+        Some((pending, since)) if *pending == modified => since.elapsed() >= RELOAD_DEBOUNCE,
+        _ => {
+            *pending_change = Some((modified, Instant::now()));
+            false
+        }
     }
 }
 
@@ -999,24 +5022,193 @@ fn build_runtime(optimize: bool) -> Runtime {
 const SECONDS_PER_MINUTE: u64 = 60;
 const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
 
+/// The unit durations get rounded to when they are displayed.
+#[derive(Copy, Clone, PartialEq)]
+enum DurationPrecision {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+}
+
+impl DurationPrecision {
+    const ALL: [Self; 3] = [Self::Nanoseconds, Self::Microseconds, Self::Milliseconds];
+
+    fn to_str(self) -> &'static str {
+        match self {
+            DurationPrecision::Nanoseconds => "Nanoseconds",
+            DurationPrecision::Microseconds => "Microseconds",
+            DurationPrecision::Milliseconds => "Milliseconds",
+        }
+    }
+
+    fn fractional_digits(self) -> u32 {
+        match self {
+            DurationPrecision::Nanoseconds => 9,
+            DurationPrecision::Microseconds => 6,
+            DurationPrecision::Milliseconds => 3,
+        }
+    }
+}
+
 fn fmt_duration(time: time::Duration) -> String {
+    fmt_duration_with_precision(time, DurationPrecision::Nanoseconds)
+}
+
+fn fmt_duration_with_precision(time: time::Duration, precision: DurationPrecision) -> String {
     let nanoseconds = time.subsec_nanoseconds();
     let total_seconds = time.whole_seconds();
-    let (minus, total_seconds, nanoseconds) = if (total_seconds | nanoseconds as i64) < 0 {
+    let (minus, mut total_seconds, nanoseconds) = if (total_seconds | nanoseconds as i64) < 0 {
         ("-", (-total_seconds) as u64, (-nanoseconds) as u32)
     } else {
         ("", total_seconds as u64, nanoseconds as u32)
     };
+    let digits = precision.fractional_digits();
+    let divisor = 10u32.pow(9 - digits);
+    let mut fraction = (nanoseconds + divisor / 2) / divisor;
+    if fraction == 10u32.pow(digits) {
+        fraction = 0;
+        total_seconds += 1;
+    }
+    let digits = digits as usize;
     let seconds = (total_seconds % SECONDS_PER_MINUTE) as u8;
     let minutes = ((total_seconds % SECONDS_PER_HOUR) / SECONDS_PER_MINUTE) as u8;
     let hours = total_seconds / SECONDS_PER_HOUR;
     if hours != 0 {
-        format!("{minus}{hours}:{minutes:02}:{seconds:02}.{nanoseconds:09}")
+        format!("{minus}{hours}:{minutes:02}:{seconds:02}.{fraction:0digits$}")
     } else {
-        format!("{minus}{minutes}:{seconds:02}.{nanoseconds:09}")
+        format!("{minus}{minutes}:{seconds:02}.{fraction:0digits$}")
     }
 }
 
+fn duration_precision_combo_box(
+    ui: &mut egui::Ui,
+    id_source: &str,
+    precision: &mut DurationPrecision,
+) {
+    ComboBox::from_id_source(id_source)
+        .selected_text(precision.to_str())
+        .show_ui(ui, |ui| {
+            for option in DurationPrecision::ALL {
+                ui.selectable_value(precision, option, option.to_str());
+            }
+        });
+}
+
+fn local_offset() -> time::UtcOffset {
+    LOCAL_OFFSET.get().copied().unwrap_or(time::UtcOffset::UTC)
+}
+
+fn fmt_time_of_day(time: SystemTime) -> String {
+    let time = time::OffsetDateTime::from(time).to_offset(local_offset());
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        time.hour(),
+        time.minute(),
+        time.second(),
+        time.millisecond(),
+    )
+}
+
+/// Formats a row of the memory view with its offset, the bytes in hex and
+/// their printable ASCII characters.
+fn fmt_memory_row(offset: usize, bytes: &[u8], bytes_per_row: usize) -> String {
+    use std::fmt::Write;
+    let mut row = format!("{offset:08X} ");
+    for byte in bytes {
+        let _ = write!(row, " {byte:02X}");
+    }
+    for _ in bytes.len()..bytes_per_row {
+        row.push_str("   ");
+    }
+    row.push_str("  ");
+    row.extend(bytes.iter().map(|&b| {
+        if b.is_ascii_graphic() || b == b' ' {
+            b as char
+        } else {
+            '.'
+        }
+    }));
+    row
+}
+
+fn fmt_diff_bytes(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    let mut text = String::new();
+    for byte in bytes.iter().take(MAX_DIFF_BYTES) {
+        let _ = write!(text, "{byte:02X} ");
+    }
+    text.pop();
+    if bytes.len() > MAX_DIFF_BYTES {
+        text.push_str(" …");
+    }
+    text
+}
+
+fn fmt_log_time(time: time::OffsetDateTime, with_date: bool) -> String {
+    let clock = format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        time.hour(),
+        time.minute(),
+        time.second(),
+        time.millisecond(),
+    );
+    if with_date {
+        format!(
+            "{}-{:02}-{:02} {clock}",
+            time.year(),
+            time.month() as u8,
+            time.day(),
+        )
+    } else {
+        clock
+    }
+}
+
+/// Shows the name of a loaded file along with how long ago it got modified.
+/// The full path is shown when hovering it.
+fn loaded_file_label(ui: &mut egui::Ui, path: &Path, modified_time: Option<SystemTime>) {
+    ui.label(RichText::new(path.file_name().unwrap_or_default().to_string_lossy()).strong())
+        .on_hover_text(path.display().to_string());
+    if let Some(modified_time) = modified_time {
+        ui.weak(format!("modified {}", fmt_ago(modified_time)))
+            .on_hover_text(format!("Modified at {}.", fmt_time_of_day(modified_time)));
+    }
+}
+
+/// Formats how long ago the time was in the largest fitting unit, such as
+/// "3m ago".
+fn fmt_ago(time: SystemTime) -> String {
+    let secs = time.elapsed().unwrap_or_default().as_secs();
+    match secs {
+        0..=9 => "just now".into(),
+        10..=59 => format!("{secs}s ago"),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+fn fmt_elapsed(instant: Instant) -> String {
+    fmt_duration_with_precision(
+        time::Duration::try_from(instant.elapsed()).unwrap_or_default(),
+        DurationPrecision::Milliseconds,
+    )
+}
+
+/// Formats how long compiling or instantiating an auto splitter took.
+fn fmt_load_time(start: Instant) -> String {
+    fmt_duration_with_precision(
+        time::Duration::try_from(start.elapsed()).unwrap_or_default(),
+        DurationPrecision::Microseconds,
+    )
+}
+
+fn fmt_bytes(bytes: usize) -> String {
+    byte_unit::Byte::from_u64(bytes as _)
+        .get_appropriate_unit(byte_unit::UnitType::Binary)
+        .to_string()
+}
+
 fn timer_state_to_str(state: TimerState) -> &'static str {
     match state {
         TimerState::NotRunning => "Not running",
@@ -1026,15 +5218,108 @@ fn timer_state_to_str(state: TimerState) -> &'static str {
     }
 }
 
-#[derive(Default)]
 struct DebuggerTimerState {
     timer_state: TimerState,
+    /// When the timer state last changed, so the change can be highlighted.
+    timer_state_changed: Option<Instant>,
     game_time: time::Duration,
     game_time_state: GameTimeState,
     split_index: usize,
-    variables: IndexMap<Box<str>, String>,
-    logs: Vec<Box<str>>,
+    variables: IndexMap<Box<str>, Variable>,
+    /// The most recent values of the variables that are numeric.
+    variable_history: HashMap<Box<str>, VecDeque<(Instant, f64)>>,
+    logs: VecDeque<LogMessage>,
     last_logs_len: usize,
+    /// The maximum amount of logs that are kept. The oldest logs are dropped
+    /// once it is exceeded.
+    max_logs: usize,
+    /// How many logs got dropped in total, so positions in the logs stay
+    /// meaningful across drops.
+    dropped_logs: usize,
+    /// How many errors got logged in total. Unlike the logs themselves, this
+    /// is never trimmed, so no error goes unnoticed.
+    error_count: usize,
+    /// Whether the timer gets started again after the auto splitter reset it.
+    auto_restart: bool,
+    auto_restart_at: Option<Instant>,
+}
+
+/// The amount of logs that are kept by default.
+const DEFAULT_MAX_LOGS: usize = 10_000;
+
+/// The range the maximum amount of logs can be adjusted in.
+const MIN_MAX_LOGS: usize = 100;
+const MAX_MAX_LOGS: usize = 1_000_000;
+
+impl Default for DebuggerTimerState {
+    fn default() -> Self {
+        Self {
+            timer_state: TimerState::default(),
+            timer_state_changed: None,
+            game_time: time::Duration::ZERO,
+            game_time_state: GameTimeState::default(),
+            split_index: 0,
+            variables: IndexMap::new(),
+            variable_history: HashMap::new(),
+            logs: VecDeque::new(),
+            last_logs_len: 0,
+            max_logs: DEFAULT_MAX_LOGS,
+            dropped_logs: 0,
+            error_count: 0,
+            auto_restart: false,
+            auto_restart_at: None,
+        }
+    }
+}
+
+struct Variable {
+    value: String,
+    /// When the value last changed, so the change can be highlighted.
+    changed: Instant,
+}
+
+struct LogMessage {
+    time: time::OffsetDateTime,
+    message: Box<str>,
+    ty: LogType,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum LogType {
+    /// A message logged by the auto splitter itself.
+    AutoSplitterMessage,
+    /// A timer action, such as a split or a reset.
+    Timer,
+    /// A process getting attached or detached.
+    Process,
+    /// A message about the debugger's own operation.
+    Runtime(LogLevel),
+    /// Marks where the auto splitter got loaded again, so the logs of the
+    /// individual runs can be told apart.
+    Separator,
+}
+
+impl LogType {
+    fn to_str(self) -> &'static str {
+        match self {
+            LogType::AutoSplitterMessage => "Auto Splitter",
+            LogType::Timer => "Timer",
+            LogType::Process => "Process",
+            LogType::Runtime(LogLevel::Debug) => "Debug",
+            LogType::Runtime(LogLevel::Info) => "Info",
+            LogType::Runtime(LogLevel::Warning) => "Warning",
+            LogType::Runtime(LogLevel::Error) => "Error",
+            LogType::Separator => "Separator",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
 }
 
 #[derive(Copy, Clone, Default, PartialEq)]
@@ -1067,7 +5352,7 @@ impl Timer for DebuggerTimer {
         let mut state = self.0.write().unwrap();
         if state.timer_state == TimerState::NotRunning {
             state.start();
-            state.logs.push("Timer started.".into());
+            state.log("Timer started.", LogType::Timer);
         }
     }
 
@@ -1075,7 +5360,7 @@ impl Timer for DebuggerTimer {
         let mut state = self.0.write().unwrap();
         if state.timer_state == TimerState::Running {
             state.split_index += 1;
-            state.logs.push("Splitted.".into());
+            state.log("Splitted.", LogType::Timer);
         }
     }
 
@@ -1083,74 +5368,181 @@ impl Timer for DebuggerTimer {
         let mut state = self.0.write().unwrap();
         if state.timer_state == TimerState::Running {
             state.split_index += 1;
-            state.logs.push("Split skipped.".into());
+            state.log("Split skipped.", LogType::Timer);
         }
     }
 
     fn undo_split(&mut self) {
         let mut state = self.0.write().unwrap();
         if state.timer_state == TimerState::Ended {
-            state.timer_state = TimerState::Running;
+            state.set_timer_state(TimerState::Running);
         }
         if state.timer_state == TimerState::Running {
             state.split_index = state.split_index.saturating_sub(1);
-            state.logs.push("Split undone.".into());
+            state.log("Split undone.", LogType::Timer);
         }
     }
 
     fn reset(&mut self) {
         let mut state = self.0.write().unwrap();
         state.reset();
-        state.logs.push("Run reset.".into());
+        state.log("Run reset.", LogType::Timer);
+        if state.auto_restart {
+            state.auto_restart_at = Some(Instant::now() + AUTO_RESTART_DELAY);
+        }
     }
 
     fn set_game_time(&mut self, time: time::Duration) {
         let mut state = self.0.write().unwrap();
         state.game_time = time;
         if state.game_time_state == GameTimeState::NotInitialized {
-            state.game_time_state = GameTimeState::Running;
+            state.set_game_time_state(GameTimeState::Running);
         }
     }
 
     fn pause_game_time(&mut self) {
-        self.0.write().unwrap().game_time_state = GameTimeState::Paused;
+        self.0
+            .write()
+            .unwrap()
+            .set_game_time_state(GameTimeState::Paused);
     }
 
     fn resume_game_time(&mut self) {
-        self.0.write().unwrap().game_time_state = GameTimeState::Running;
+        self.0
+            .write()
+            .unwrap()
+            .set_game_time_state(GameTimeState::Running);
     }
 
     fn set_variable(&mut self, key: &str, value: &str) {
-        let mut guard = self.0.write().unwrap();
-        let s = guard.variables.entry(key.into()).or_default();
-        s.clear();
-        s.push_str(value);
+        self.0.write().unwrap().set_variable(key, value);
     }
 
     fn log(&mut self, message: std::fmt::Arguments<'_>) {
-        self.0.write().unwrap().logs.push(match message.as_str() {
+        let message: Box<str> = match message.as_str() {
             Some(m) => m.into(),
             None => message.to_string().into(),
-        });
+        };
+        self.0
+            .write()
+            .unwrap()
+            .log(message, LogType::AutoSplitterMessage);
     }
 }
 
 impl DebuggerTimerState {
     fn start(&mut self) {
         if self.timer_state == TimerState::NotRunning {
-            self.timer_state = TimerState::Running;
+            self.set_timer_state(TimerState::Running);
         }
     }
 
     fn reset(&mut self) {
-        self.timer_state = TimerState::NotRunning;
+        self.set_timer_state(TimerState::NotRunning);
         self.split_index = 0;
         self.game_time = time::Duration::ZERO;
-        self.game_time_state = GameTimeState::NotInitialized;
+        self.set_game_time_state(GameTimeState::NotInitialized);
         self.variables.clear();
+        self.variable_history.clear();
     }
 
     fn clear(&mut self) {
         self.reset();
     }
+
+    fn set_variable(&mut self, key: &str, value: &str) {
+        match self.variables.get_mut(key) {
+            Some(variable) => {
+                if variable.value != value {
+                    variable.value.clear();
+                    variable.value.push_str(value);
+                    variable.changed = Instant::now();
+                }
+            }
+            None => {
+                self.variables.insert(
+                    key.into(),
+                    Variable {
+                        value: value.into(),
+                        changed: Instant::now(),
+                    },
+                );
+            }
+        }
+
+        if let Ok(value) = value.trim().parse::<f64>() {
+            let history = self.variable_history.entry(key.into()).or_default();
+            if history.len() == VARIABLE_HISTORY_CAPACITY {
+                history.pop_front();
+            }
+            history.push_back((Instant::now(), value));
+        }
+    }
+
+    fn auto_restart_if_due(&mut self) {
+        if self.auto_restart_at.is_some_and(|at| Instant::now() >= at) {
+            self.auto_restart_at = None;
+            if self.timer_state == TimerState::NotRunning {
+                self.start();
+                self.log("Timer automatically restarted.", LogType::Timer);
+            }
+        }
+    }
+
+    fn set_timer_state(&mut self, timer_state: TimerState) {
+        if self.timer_state != timer_state {
+            self.timer_state = timer_state;
+            self.timer_state_changed = Some(Instant::now());
+        }
+    }
+
+    /// Changes the state of the game time. Only actual changes are logged, as
+    /// the game time gets set on every tick.
+    fn set_game_time_state(&mut self, game_time_state: GameTimeState) {
+        if self.game_time_state != game_time_state {
+            self.log(
+                format!(
+                    "Game time state changed from {} to {}.",
+                    self.game_time_state.to_str(),
+                    game_time_state.to_str(),
+                ),
+                LogType::Runtime(LogLevel::Debug),
+            );
+            self.game_time_state = game_time_state;
+        }
+    }
+
+    fn log(&mut self, message: impl Into<Box<str>>, ty: LogType) {
+        if ty == LogType::Runtime(LogLevel::Error) {
+            self.error_count += 1;
+        }
+        self.logs.push_back(LogMessage {
+            time: time::OffsetDateTime::now_utc().to_offset(local_offset()),
+            message: message.into(),
+            ty,
+        });
+        self.drop_excess_logs();
+    }
+
+    fn set_max_logs(&mut self, max_logs: usize) {
+        self.max_logs = max_logs;
+        self.drop_excess_logs();
+    }
+
+    /// Removes all the logs. They count as dropped, so positions in the logs
+    /// stay meaningful.
+    fn clear_logs(&mut self) {
+        self.dropped_logs += self.logs.len();
+        self.logs.clear();
+        self.last_logs_len = 0;
+    }
+
+    fn drop_excess_logs(&mut self) {
+        let excess = self.logs.len().saturating_sub(self.max_logs);
+        if excess != 0 {
+            self.logs.drain(..excess);
+            self.last_logs_len = self.last_logs_len.saturating_sub(excess);
+            self.dropped_logs += excess;
+        }
+    }
 }
@@ -0,0 +1,132 @@
+//! Static information about a WASM module, such as the functions it imports and
+//! exports. This helps confirming that an auto splitter got built correctly,
+//! for example that it actually contains debug information.
+
+use wasmparser::{ExternalKind, MemoryType, Parser, Payload, TypeRef};
+
+/// The size of a page of WASM memory.
+pub const PAGE_SIZE: u64 = 64 << 10;
+
+pub struct ModuleInfo {
+    /// The size of the WASM file in bytes.
+    pub size: usize,
+    /// The names of the exported functions.
+    pub exports: Vec<String>,
+    /// The imported host functions, formatted as `module.name`.
+    pub imports: Vec<String>,
+    /// The memories of the module, including the imported ones.
+    pub memories: Vec<Memory>,
+    /// The names of the custom sections.
+    pub custom_sections: Vec<String>,
+}
+
+/// The size of a memory in pages.
+pub struct Memory {
+    pub initial: u64,
+    pub maximum: Option<u64>,
+}
+
+impl From<MemoryType> for Memory {
+    fn from(ty: MemoryType) -> Self {
+        Self {
+            initial: ty.initial,
+            maximum: ty.maximum,
+        }
+    }
+}
+
+impl ModuleInfo {
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let mut info = Self {
+            size: data.len(),
+            exports: Vec::new(),
+            imports: Vec::new(),
+            memories: Vec::new(),
+            custom_sections: Vec::new(),
+        };
+
+        for payload in Parser::new(0).parse_all(data) {
+            match payload? {
+                Payload::ImportSection(imports) => {
+                    for import in imports {
+                        let import = import?;
+                        match import.ty {
+                            TypeRef::Func(_) => info
+                                .imports
+                                .push(format!("{}.{}", import.module, import.name)),
+                            TypeRef::Memory(ty) => info.memories.push(ty.into()),
+                            _ => {}
+                        }
+                    }
+                }
+                Payload::MemorySection(memories) => {
+                    for memory in memories {
+                        info.memories.push(memory?.into());
+                    }
+                }
+                Payload::ExportSection(exports) => {
+                    for export in exports {
+                        let export = export?;
+                        if export.kind == ExternalKind::Func {
+                            info.exports.push(export.name.to_owned());
+                        }
+                    }
+                }
+                Payload::CustomSection(section) => {
+                    info.custom_sections.push(section.name().to_owned());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Whether the module contains DWARF debug information, which is needed
+    /// for stepping through the source code.
+    pub fn has_debug_info(&self) -> bool {
+        self.custom_sections
+            .iter()
+            .any(|name| name.starts_with(".debug_"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // Header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // Type section: () -> ()
+        0x02, 0x13, 0x01, 0x03, b'e', b'n', b'v', 0x0b, b't', b'i', b'm', b'e', b'r', b'_', b's',
+        b't', b'a', b'r', b't', 0x00, 0x00, // Import section: env.timer_start
+        0x03, 0x02, 0x01, 0x00, // Function section
+        0x05, 0x04, 0x01, 0x01, 0x01, 0x02, // Memory section: 1 to 2 pages
+        0x07, 0x13, 0x02, 0x06, b'u', b'p', b'd', b'a', b't', b'e', 0x00, 0x01, 0x06, b'm', b'e',
+        b'm', b'o', b'r', b'y', 0x02, 0x00, // Export section: update, memory
+        0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b, // Code section
+        0x00, 0x0c, 0x0b, b'.', b'd', b'e', b'b', b'u', b'g', b'_', b'i', b'n', b'f', b'o',
+    ];
+
+    #[test]
+    fn test_parse() {
+        let info = ModuleInfo::parse(MODULE).unwrap();
+        assert_eq!(info.size, MODULE.len());
+        assert_eq!(info.exports, ["update"]);
+        assert_eq!(info.imports, ["env.timer_start"]);
+        assert!(matches!(
+            info.memories[..],
+            [Memory {
+                initial: 1,
+                maximum: Some(2),
+            }]
+        ));
+        assert_eq!(info.custom_sections, [".debug_info"]);
+        assert!(info.has_debug_info());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(ModuleInfo::parse(b"not a wasm file").is_err());
+    }
+}
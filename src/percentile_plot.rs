@@ -0,0 +1,95 @@
+//! Converts the histogram of the tick times into the data of the Performance
+//! plot, which shows how the samples are distributed over the percentiles.
+//! Empty histograms and non-finite values are handled here, so the plot
+//! doesn't end up with broken bars or labels.
+
+use hdrhistogram::Histogram;
+
+pub struct PercentileBar {
+    /// The percentile in the middle of the bar.
+    pub mid_x: f64,
+    /// The width of the bar in percentiles.
+    pub width: f64,
+    /// The percentage of samples the bar covers.
+    pub height: f64,
+}
+
+/// Splits the recorded values into bars that span the percentiles they cover.
+/// An empty histogram results in no bars.
+pub fn bars(histogram: &Histogram<u64>) -> Vec<PercentileBar> {
+    if histogram.is_empty() {
+        return Vec::new();
+    }
+    let scale_y = 100.0 / histogram.len() as f64;
+    let mut right_x = 0.0;
+    histogram
+        .iter_recorded()
+        .map(|bar| {
+            let left_x = right_x;
+            right_x = bar.percentile();
+            PercentileBar {
+                mid_x: 0.5 * (left_x + right_x),
+                width: right_x - left_x,
+                height: scale_y * bar.count_since_last_iteration() as f64,
+            }
+        })
+        .collect()
+}
+
+/// The percentile the mean of the values falls on. There is no mean if the
+/// histogram is empty.
+pub fn mean_percentile(histogram: &Histogram<u64>) -> Option<f64> {
+    if histogram.is_empty() {
+        return None;
+    }
+    Some(histogram.percentile_below(histogram.mean() as _)).filter(|x| x.is_finite())
+}
+
+/// Formats a label of the percentile axis, using as many of the `chars` as
+/// are available.
+pub fn fmt_axis_label(x: f64, chars: usize) -> String {
+    if !x.is_finite() {
+        return String::new();
+    }
+    let mut text = x.to_string();
+    if chars >= text.len() + 2 {
+        text.push_str("th");
+    }
+    if chars >= text.len() + 11 {
+        text.push_str(" percentile");
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_empty_histogram() {
+        let histogram = Histogram::<u64>::new(1).unwrap();
+        assert!(bars(&histogram).is_empty());
+        assert_eq!(mean_percentile(&histogram), None);
+    }
+
+    #[test]
+    fn test_bars_cover_all_samples() {
+        let mut histogram = Histogram::<u64>::new(3).unwrap();
+        for value in [10, 10, 20, 40] {
+            histogram += value;
+        }
+        let bars = bars(&histogram);
+        assert_eq!(bars.len(), 3);
+        let total: f64 = bars.iter().map(|bar| bar.height).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+        assert!(mean_percentile(&histogram).is_some_and(f64::is_finite));
+    }
+
+    #[test]
+    fn test_non_finite_axis_label() {
+        assert_eq!(fmt_axis_label(f64::NAN, 20), "");
+        assert_eq!(fmt_axis_label(f64::INFINITY, 20), "");
+        assert_eq!(fmt_axis_label(50.0, 20), "50th percentile");
+        assert_eq!(fmt_axis_label(50.0, 2), "50");
+    }
+}
@@ -0,0 +1,118 @@
+//! A profile run records the tick times, memory usage and handles of the auto
+//! splitter for a fixed duration and summarizes them in a report once it is
+//! done.
+
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use hdrhistogram::Histogram;
+use livesplit_auto_splitting::time;
+
+use crate::{fmt_bytes, fmt_duration, LogMessage};
+
+pub struct ProfileRun {
+    start: Instant,
+    duration: Duration,
+    first_log: usize,
+    tick_times: Histogram<u64>,
+    memory_usage_sum: u128,
+    peak_memory_usage: usize,
+    handles_sum: u128,
+    peak_handles: u64,
+}
+
+impl ProfileRun {
+    /// Starts a new profile run. The logs starting at `first_log` are
+    /// included in the report, where the position also counts the logs that
+    /// got dropped.
+    pub fn new(duration: Duration, first_log: usize) -> Self {
+        Self {
+            start: Instant::now(),
+            duration,
+            first_log,
+            tick_times: Histogram::new(3).unwrap(),
+            memory_usage_sum: 0,
+            peak_memory_usage: 0,
+            handles_sum: 0,
+            peak_handles: 0,
+        }
+    }
+
+    pub fn record(&mut self, tick_time: Duration, memory_usage: usize, handles: u64) {
+        self.tick_times += tick_time.as_nanos() as u64;
+        self.memory_usage_sum += memory_usage as u128;
+        self.peak_memory_usage = self.peak_memory_usage.max(memory_usage);
+        self.handles_sum += handles as u128;
+        self.peak_handles = self.peak_handles.max(handles);
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.start.elapsed())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Summarizes the profile run. `dropped_logs` is how many logs got
+    /// dropped before the ones that are still kept.
+    pub fn report(&self, logs: &VecDeque<LogMessage>, dropped_logs: usize) -> String {
+        let mut report = String::new();
+        let ticks = self.tick_times.len();
+        let _ = writeln!(report, "Auto Splitter Profile");
+        let _ = writeln!(
+            report,
+            "Duration: {}",
+            fmt_duration(time::Duration::try_from(self.duration).unwrap_or_default()),
+        );
+        let _ = writeln!(report, "Ticks: {ticks}");
+
+        if ticks != 0 {
+            let nanoseconds = |nanos: u64| fmt_duration(time::Duration::nanoseconds(nanos as _));
+            let _ = writeln!(report);
+            let _ = writeln!(report, "Tick Times");
+            let _ = writeln!(
+                report,
+                "  Average: {}",
+                nanoseconds(self.tick_times.mean() as _)
+            );
+            let _ = writeln!(report, "  Minimum: {}", nanoseconds(self.tick_times.min()));
+            for percentile in [50.0, 90.0, 99.0, 99.9] {
+                let _ = writeln!(
+                    report,
+                    "  {percentile}th Percentile: {}",
+                    nanoseconds(self.tick_times.value_at_percentile(percentile)),
+                );
+            }
+            let _ = writeln!(report, "  Maximum: {}", nanoseconds(self.tick_times.max()));
+
+            let _ = writeln!(report);
+            let _ = writeln!(report, "Memory");
+            let _ = writeln!(
+                report,
+                "  Average: {}",
+                fmt_bytes((self.memory_usage_sum / ticks as u128) as usize),
+            );
+            let _ = writeln!(report, "  Peak: {}", fmt_bytes(self.peak_memory_usage));
+
+            let _ = writeln!(report);
+            let _ = writeln!(report, "Handles");
+            let _ = writeln!(report, "  Average: {}", self.handles_sum / ticks as u128);
+            let _ = writeln!(report, "  Peak: {}", self.peak_handles);
+        }
+
+        let logs = logs
+            .iter()
+            .skip(self.first_log.saturating_sub(dropped_logs));
+        let _ = writeln!(report);
+        let _ = writeln!(report, "Logs ({})", logs.len());
+        for log in logs {
+            let _ = writeln!(report, "  {}", log.message);
+        }
+
+        report
+    }
+}
@@ -0,0 +1,95 @@
+//! Publishes the state of the run, so it can be mirrored by external timers.
+//! Clients connect via TCP and receive a JSON snapshot of the run state as a
+//! single line whenever it changes.
+
+use std::{
+    io::{self, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{timer_state_to_str, DebuggerTimer};
+
+/// How often the run state is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long a client may take to accept a snapshot. Clients that don't keep
+/// up are disconnected, so they can't stall the other clients.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Serialize, PartialEq)]
+pub struct RunStateSnapshot {
+    pub timer_state: &'static str,
+    pub split_index: usize,
+    pub game_time_secs: f64,
+    pub game_time_state: &'static str,
+}
+
+impl RunStateSnapshot {
+    pub fn new(timer: &DebuggerTimer) -> Self {
+        let state = timer.0.read().unwrap();
+        Self {
+            timer_state: timer_state_to_str(state.timer_state),
+            split_index: state.split_index,
+            game_time_secs: state.game_time.as_seconds_f64(),
+            game_time_state: state.game_time_state.to_str(),
+        }
+    }
+
+    fn to_line(&self) -> String {
+        let mut line = serde_json::to_string(self).unwrap_or_default();
+        line.push('\n');
+        line
+    }
+}
+
+/// Starts listening on the address and publishes every change of the run
+/// state to all the connected clients.
+pub fn spawn_server(address: &str, timer: DebuggerTimer) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+    let clients = Arc::new(Mutex::new(Vec::<TcpStream>::new()));
+
+    thread::Builder::new()
+        .name("Run State Listener Thread".into())
+        .spawn({
+            let clients = clients.clone();
+            let timer = timer.clone();
+            move || {
+                for mut stream in listener.incoming().flatten() {
+                    if stream.set_write_timeout(Some(WRITE_TIMEOUT)).is_err() {
+                        continue;
+                    }
+                    let line = RunStateSnapshot::new(&timer).to_line();
+                    if stream.write_all(line.as_bytes()).is_ok() {
+                        clients.lock().unwrap().push(stream);
+                    }
+                }
+            }
+        })?;
+
+    thread::Builder::new()
+        .name("Run State Publisher Thread".into())
+        .spawn(move || {
+            let mut last_snapshot = None;
+            loop {
+                thread::sleep(POLL_INTERVAL);
+                let snapshot = RunStateSnapshot::new(&timer);
+                if last_snapshot.as_ref() == Some(&snapshot) {
+                    continue;
+                }
+                let line = snapshot.to_line();
+                // The clients are written to without holding the lock, so new
+                // clients can still connect while a slow client is written to.
+                let mut current_clients = std::mem::take(&mut *clients.lock().unwrap());
+                current_clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+                clients.lock().unwrap().append(&mut current_clients);
+                last_snapshot = Some(snapshot);
+            }
+        })?;
+
+    Ok(())
+}
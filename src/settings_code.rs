@@ -0,0 +1,126 @@
+//! Converts settings maps into Rust code that builds the same map with the
+//! `asr` crate. This way a settings map that got tweaked in the debugger can be
+//! pasted into an auto splitter as its defaults or into a test.
+
+use std::fmt::Write;
+
+use livesplit_auto_splitting::settings;
+
+const INDENT: &str = "    ";
+
+/// Formats the settings map as a Rust block expression that evaluates to an
+/// `asr::settings::Map`.
+pub fn map_to_code(map: &settings::Map) -> String {
+    let mut code = String::new();
+    write_map(&mut code, map, 0);
+    code
+}
+
+fn write_map(code: &mut String, map: &settings::Map, depth: usize) {
+    let indent = INDENT.repeat(depth + 1);
+    code.push_str("{\n");
+    let _ = writeln!(code, "{indent}let map = asr::settings::Map::new();");
+    for (key, value) in map.iter() {
+        let _ = write!(code, "{indent}map.insert({key:?}, ");
+        write_value(code, value, depth + 1);
+        code.push_str(");\n");
+    }
+    let _ = write!(code, "{indent}map\n{}}}", INDENT.repeat(depth));
+}
+
+fn write_list(code: &mut String, list: &settings::List, depth: usize) {
+    let indent = INDENT.repeat(depth + 1);
+    code.push_str("{\n");
+    let _ = writeln!(code, "{indent}let list = asr::settings::List::new();");
+    for value in list.iter() {
+        let _ = write!(code, "{indent}list.push(");
+        write_value(code, value, depth + 1);
+        code.push_str(");\n");
+    }
+    let _ = write!(code, "{indent}list\n{}}}", INDENT.repeat(depth));
+}
+
+fn write_value(code: &mut String, value: &settings::Value, depth: usize) {
+    match value {
+        settings::Value::Map(v) => write_map(code, v, depth),
+        settings::Value::List(v) => write_list(code, v, depth),
+        settings::Value::Bool(v) => {
+            let _ = write!(code, "{v}");
+        }
+        settings::Value::I64(v) => {
+            let _ = write!(code, "{v}_i64");
+        }
+        settings::Value::F64(v) => {
+            if v.is_nan() {
+                code.push_str("f64::NAN");
+            } else if v.is_infinite() {
+                code.push_str(if *v > 0.0 {
+                    "f64::INFINITY"
+                } else {
+                    "f64::NEG_INFINITY"
+                });
+            } else {
+                let _ = write!(code, "{v:?}_f64");
+            }
+        }
+        settings::Value::String(v) => {
+            let _ = write!(code, "{:?}", &**v);
+        }
+        v => write_unsupported(code, &format!("{v:?}")),
+    }
+}
+
+/// Values that have no equivalent in the `asr` crate become a compile error,
+/// so the generated code doesn't silently lose them.
+fn write_unsupported(code: &mut String, value: &str) {
+    let _ = write!(
+        code,
+        "compile_error!(\"unsupported settings value: {}\")",
+        value.escape_default()
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::settings_json;
+
+    #[test]
+    fn test_map_to_code() {
+        let map = settings_json::parse_map(
+            r#"{"count":-3,"enabled":true,"list":[1,{"nested":2.0}],"name":"a \"b\"","ratio":0.5}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            map_to_code(&map),
+            r#"{
+    let map = asr::settings::Map::new();
+    map.insert("count", -3_i64);
+    map.insert("enabled", true);
+    map.insert("list", {
+        let list = asr::settings::List::new();
+        list.push(1_i64);
+        list.push({
+            let map = asr::settings::Map::new();
+            map.insert("nested", 2.0_f64);
+            map
+        });
+        list
+    });
+    map.insert("name", "a \"b\"");
+    map.insert("ratio", 0.5_f64);
+    map
+}"#
+        );
+    }
+
+    #[test]
+    fn test_unsupported_value() {
+        let mut code = String::new();
+        write_unsupported(&mut code, r#"Unknown("a")"#);
+        assert_eq!(
+            code,
+            r#"compile_error!("unsupported settings value: Unknown(\"a\")")"#
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Compares two settings maps and lists all the keys that got added, removed
+//! or changed. Nested maps and lists are compared recursively, so only the
+//! innermost values that actually differ are reported.
+
+use livesplit_auto_splitting::settings;
+
+pub enum Difference {
+    Added(settings::Value),
+    Removed(settings::Value),
+    Changed(settings::Value, settings::Value),
+}
+
+/// Lists the differences between the two settings maps, each along with the
+/// dotted path of the value that differs.
+pub fn diff_maps(old: &settings::Map, new: &settings::Map) -> Vec<(String, Difference)> {
+    let mut differences = Vec::new();
+    diff_maps_at(&mut differences, "", old, new);
+    differences
+}
+
+fn diff_maps_at(
+    differences: &mut Vec<(String, Difference)>,
+    path: &str,
+    old: &settings::Map,
+    new: &settings::Map,
+) {
+    let key_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => diff_values(differences, key_path(key), old_value, new_value),
+            None => differences.push((key_path(key), Difference::Removed(old_value.clone()))),
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            differences.push((key_path(key), Difference::Added(new_value.clone())));
+        }
+    }
+}
+
+fn diff_values(
+    differences: &mut Vec<(String, Difference)>,
+    path: String,
+    old: &settings::Value,
+    new: &settings::Value,
+) {
+    match (old, new) {
+        (settings::Value::Map(old), settings::Value::Map(new)) => {
+            diff_maps_at(differences, &path, old, new);
+        }
+        (settings::Value::List(old), settings::Value::List(new)) => {
+            let old = old.iter().collect::<Vec<_>>();
+            let new = new.iter().collect::<Vec<_>>();
+            for i in 0..old.len().max(new.len()) {
+                let item_path = format!("{path}[{i}]");
+                match (old.get(i), new.get(i)) {
+                    (Some(old), Some(new)) => diff_values(differences, item_path, old, new),
+                    (Some(old), None) => {
+                        differences.push((item_path, Difference::Removed((*old).clone())))
+                    }
+                    (None, Some(new)) => {
+                        differences.push((item_path, Difference::Added((*new).clone())))
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+        _ if leaf_values_equal(old, new) => {}
+        _ => differences.push((path, Difference::Changed(old.clone(), new.clone()))),
+    }
+}
+
+fn leaf_values_equal(a: &settings::Value, b: &settings::Value) -> bool {
+    match (a, b) {
+        (settings::Value::Bool(a), settings::Value::Bool(b)) => a == b,
+        (settings::Value::I64(a), settings::Value::I64(b)) => a == b,
+        (settings::Value::F64(a), settings::Value::F64(b)) => a.to_bits() == b.to_bits(),
+        (settings::Value::String(a), settings::Value::String(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::settings_json::parse_map;
+
+    fn diff(old: &str, new: &str) -> Vec<(String, &'static str)> {
+        diff_maps(&parse_map(old).unwrap(), &parse_map(new).unwrap())
+            .into_iter()
+            .map(|(path, difference)| {
+                let kind = match difference {
+                    Difference::Added(_) => "added",
+                    Difference::Removed(_) => "removed",
+                    Difference::Changed(_, _) => "changed",
+                };
+                (path, kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_maps() {
+        assert!(diff(r#"{"a": 1, "b": "x"}"#, r#"{"a": 1, "b": "x"}"#).is_empty());
+        assert_eq!(
+            diff(r#"{"a": 1, "b": true}"#, r#"{"a": 2, "c": false}"#),
+            [
+                ("a".to_string(), "changed"),
+                ("b".to_string(), "removed"),
+                ("c".to_string(), "added"),
+            ],
+        );
+        assert_eq!(
+            diff(r#"{"a": 1}"#, r#"{"a": "1"}"#),
+            [("a".to_string(), "changed")],
+        );
+    }
+
+    #[test]
+    fn test_diff_nested() {
+        assert_eq!(
+            diff(
+                r#"{"m": {"x": 1, "y": [1, 2, 3]}}"#,
+                r#"{"m": {"x": 1, "y": [1, 5]}}"#,
+            ),
+            [
+                ("m.y[1]".to_string(), "changed"),
+                ("m.y[2]".to_string(), "removed"),
+            ],
+        );
+    }
+}
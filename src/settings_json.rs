@@ -0,0 +1,121 @@
+//! Converts settings maps to and from JSON. Maps become JSON objects, lists
+//! become JSON arrays and all the other values map to their natural JSON
+//! counterparts.
+
+use anyhow::{bail, Context};
+use livesplit_auto_splitting::settings;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// Parses a settings map from a JSON object.
+pub fn parse_map(json: &str) -> anyhow::Result<settings::Map> {
+    let value: JsonValue =
+        serde_json::from_str(json).context("The settings map is not valid JSON.")?;
+    let JsonValue::Object(object) = value else {
+        bail!("The settings map needs to be a JSON object.");
+    };
+    map_from_json(object)
+}
+
+/// Formats a settings map as a pretty printed JSON object.
+pub fn map_to_string_pretty(map: &settings::Map) -> String {
+    format!("{:#}", map_to_json(map))
+}
+
+/// Formats a single settings value as compact JSON.
+pub fn value_to_string(value: &settings::Value) -> String {
+    value_to_json(value).to_string()
+}
+
+fn map_from_json(object: JsonMap<String, JsonValue>) -> anyhow::Result<settings::Map> {
+    let mut map = settings::Map::new();
+    for (key, value) in object {
+        let value = value_from_json(value)
+            .with_context(|| format!("Invalid value for the key `{key}`."))?;
+        map.insert(key.as_str().into(), value);
+    }
+    Ok(map)
+}
+
+fn value_from_json(value: JsonValue) -> anyhow::Result<settings::Value> {
+    Ok(match value {
+        JsonValue::Null => bail!("Null values are not supported."),
+        JsonValue::Bool(v) => settings::Value::Bool(v),
+        JsonValue::Number(v) => match v.as_i64() {
+            Some(v) => settings::Value::I64(v),
+            None => settings::Value::F64(v.as_f64().context("The number is out of range.")?),
+        },
+        JsonValue::String(v) => settings::Value::String(v.into()),
+        JsonValue::Array(values) => {
+            let mut list = settings::List::new();
+            for value in values {
+                list.push(value_from_json(value)?);
+            }
+            settings::Value::List(list)
+        }
+        JsonValue::Object(object) => settings::Value::Map(map_from_json(object)?),
+    })
+}
+
+/// Converts a settings map to a JSON object.
+pub fn map_to_json(map: &settings::Map) -> JsonValue {
+    JsonValue::Object(
+        map.iter()
+            .map(|(key, value)| (key.to_string(), value_to_json(value)))
+            .collect(),
+    )
+}
+
+fn value_to_json(value: &settings::Value) -> JsonValue {
+    match value {
+        settings::Value::Map(v) => map_to_json(v),
+        settings::Value::List(v) => JsonValue::Array(v.iter().map(value_to_json).collect()),
+        settings::Value::Bool(v) => JsonValue::Bool(*v),
+        settings::Value::I64(v) => JsonValue::from(*v),
+        settings::Value::F64(v) => {
+            serde_json::Number::from_f64(*v).map_or(JsonValue::Null, JsonValue::Number)
+        }
+        settings::Value::String(v) => JsonValue::String(v.to_string()),
+        _ => JsonValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let json = r#"{"bool":true,"float":0.5,"int":-3,"list":[1,"two",[false]],"map":{"nested":"value"}}"#;
+        let map = parse_map(json).unwrap();
+        assert_eq!(map_to_json(&map).to_string(), json);
+    }
+
+    #[test]
+    fn test_parse_map_nested() {
+        let map = parse_map(r#"{"list":[1,"two",[false]],"map":{"nested":"value"}}"#).unwrap();
+        assert!(matches!(
+            map.get("list"),
+            Some(settings::Value::List(list)) if list.iter().count() == 3
+        ));
+        assert!(matches!(
+            map.get("map"),
+            Some(settings::Value::Map(map)) if map.get("nested").is_some()
+        ));
+    }
+
+    #[test]
+    fn test_parse_map_rejects_invalid_input() {
+        assert!(parse_map("").is_err());
+        assert!(parse_map("[1, 2]").is_err());
+        assert!(parse_map(r#"{"key": null}"#).is_err());
+        assert!(parse_map(r#"{"list": [null]}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_map_numbers() {
+        let map = parse_map(r#"{"int": 1, "float": 1.5, "big": 18446744073709551615}"#).unwrap();
+        assert!(matches!(map.get("int"), Some(settings::Value::I64(1))));
+        assert!(matches!(map.get("float"), Some(settings::Value::F64(v)) if *v == 1.5));
+        assert!(matches!(map.get("big"), Some(settings::Value::F64(_))));
+    }
+}
@@ -0,0 +1,107 @@
+//! A snapshot captures the memory of an auto splitter along with its settings
+//! map and the state of the timer. The runtime doesn't allow writing the
+//! memory of an auto splitter, so snapshots can't be restored. Instead they are
+//! compared to find the byte ranges that changed in between.
+//!
+//! A snapshot file starts with a header line, followed by a line of JSON with
+//! the information about the snapshot and the raw memory afterwards.
+
+use std::{fs, ops::Range, path::Path};
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+
+const HEADER: &[u8] = b"ASR-DEBUGGER-SNAPSHOT\n";
+
+pub struct Snapshot {
+    pub info: SnapshotInfo,
+    pub memory: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub timer_state: String,
+    pub split_index: usize,
+    pub game_time_secs: f64,
+    pub settings_map: serde_json::Value,
+}
+
+impl Snapshot {
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let mut data = HEADER.to_vec();
+        serde_json::to_writer(&mut data, &self.info)?;
+        data.push(b'\n');
+        data.extend_from_slice(&self.memory);
+        fs::write(path, data).context("Failed writing the file.")
+    }
+
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let data = fs::read(path).context("Failed reading the file.")?;
+        Self::parse(&data)
+    }
+
+    fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let Some(rem) = data.strip_prefix(HEADER) else {
+            bail!("The file is not a snapshot.");
+        };
+        let end = rem
+            .iter()
+            .position(|&b| b == b'\n')
+            .context("The snapshot is truncated.")?;
+        let info = serde_json::from_slice(&rem[..end]).context("The snapshot is invalid.")?;
+        Ok(Self {
+            info,
+            memory: rem[end + 1..].to_vec(),
+        })
+    }
+}
+
+/// Reads the memory stored in a file, which is either a snapshot or a raw
+/// memory dump.
+pub fn read_memory(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let data = fs::read(path).context("Failed reading the file.")?;
+    if data.starts_with(HEADER) {
+        Ok(Snapshot::parse(&data)?.memory)
+    } else {
+        Ok(data)
+    }
+}
+
+/// Finds the ranges of bytes that differ between the two memories. If one of
+/// them is longer, the additional bytes count as changed as well.
+pub fn diff(old: &[u8], new: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for i in 0..old.len().max(new.len()) {
+        if old.get(i) != new.get(i) {
+            start.get_or_insert(i);
+        } else if let Some(start) = start.take() {
+            ranges.push(start..i);
+        }
+    }
+    if let Some(start) = start {
+        ranges.push(start..old.len().max(new.len()));
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_equal() {
+        assert!(diff(&[1, 2, 3], &[1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn test_diff_ranges() {
+        assert_eq!(diff(&[0, 1, 2, 3, 4, 5], &[0, 9, 9, 3, 4, 9]), [1..3, 5..6]);
+    }
+
+    #[test]
+    fn test_diff_different_lengths() {
+        assert_eq!(diff(&[1, 2], &[1, 2, 3, 4]), [2..4]);
+        assert_eq!(diff(&[1, 2, 3], &[7]), [0..3]);
+    }
+}